@@ -1,5 +1,9 @@
 use std::collections::HashMap;
 
+use anyhow::Result;
+
+use crate::solution::Solution;
+
 fn get_numbers(line: &str) -> Vec<u32> {
     line.chars()
         .filter_map(|char| char.to_digit(10))
@@ -45,23 +49,40 @@ fn calibrate(numbers: Vec<u32>) -> u32 {
     }
 }
 
-pub fn part1(lines: &Vec<String>) -> u32 {
-    lines.iter()
-        .map(|string| get_numbers(string.as_str()))
-        .map(calibrate)
-        .sum::<u32>()
-}
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input<'a> = Vec<&'a str>;
+    type Answer = u32;
+
+    fn parse(input: &str) -> Result<Vec<&str>> {
+        Ok(input.lines().collect())
+    }
 
-pub fn part2(lines: &Vec<String>) -> u32 {
-    lines.iter()
-        .map(|string| get_text_numbers(string.as_str()))
-        .map(calibrate)
-        .sum::<u32>()
+    fn part1(lines: &Vec<&str>) -> Result<u32> {
+        let result = lines.iter()
+            .map(|line| get_numbers(line))
+            .map(calibrate)
+            .sum::<u32>();
+
+        Ok(result)
+    }
+
+    fn part2(lines: &Vec<&str>) -> Result<u32> {
+        let result = lines.iter()
+            .map(|line| get_text_numbers(line))
+            .map(calibrate)
+            .sum::<u32>();
+
+        Ok(result)
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::day1::{calibrate, get_numbers, get_text_numbers, part1, part2};
+    use crate::day1::{calibrate, Day1, get_numbers, get_text_numbers};
+    use crate::example;
+    use crate::solution::Solution;
 
     #[test]
     pub fn test_get_numbers() {
@@ -93,28 +114,13 @@ mod tests {
 
     #[test]
     pub fn test_part_1() {
-        let input: Vec<String> = vec![
-            "1abc2".into(),
-            "pqr3stu8vwx".into(),
-            "a1b2c3d4e5f".into(),
-            "treb7uchet".into(),
-        ];
-
-        assert_eq!(part1(&input), 142)
+        let input = example::input(1, "part1");
+        assert_eq!(Day1::part1(&Day1::parse(&input).unwrap()).unwrap(), 142)
     }
 
     #[test]
     pub fn test_part_2() {
-        let input: Vec<String> = vec![
-            "two1nine".into(),
-            "eightwothree".into(),
-            "abcone2threexyz".into(),
-            "xtwone3four".into(),
-            "4nineeightseven2".into(),
-            "zoneight234".into(),
-            "7pqrstsixteen".into(),
-        ];
-
-        assert_eq!(part2(&input), 281)
+        let input = example::input(1, "part2");
+        assert_eq!(Day1::part2(&Day1::parse(&input).unwrap()).unwrap(), 281)
     }
 }
@@ -1,6 +1,6 @@
 use std::str::FromStr;
 
-use anyhow::{anyhow, Result};
+use crate::error::ParseError;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct Cubes(pub u32, pub u32, pub u32);
@@ -11,50 +11,66 @@ pub struct Game {
     pub rounds: Vec<Cubes>,
 }
 
-fn get_count(input: &Vec<(u32, &str)>, color: &str) -> u32 {
+fn get_count(input: &[(u32, &str)], color: &str) -> u32 {
     input.iter()
         .find(|(_, description)| *description == color)
         .map_or(0, |(count, _)| *count)
 }
 
-fn parse_round(input: &str) -> Cubes {
+fn parse_cubes(input: &str) -> Result<(u32, &str), ParseError> {
+    let (count, color) = input.split_once(' ')
+        .ok_or_else(|| ParseError::at_end(2, input, "` `"))?;
+
+    let count = count.parse::<u32>()
+        .map_err(|_| ParseError::at(2, input, count, "cube count"))?;
+
+    match color {
+        "red" | "green" | "blue" => Ok((count, color)),
+        _ => Err(ParseError::at(2, input, color, "red, green or blue"))
+    }
+}
+
+fn parse_round(input: &str) -> Result<Cubes, ParseError> {
     let cubes: Vec<(u32, &str)> = input.split(", ")
-        .map_while(|cubes| cubes.split_once(' ')
-            .and_then(|(count, color)| Some((count.parse::<u32>().ok()?, color))))
-        .into_iter()
-        .collect();
+        .map(|cubes| parse_cubes(cubes).map_err(|err| err.within(input, cubes)))
+        .collect::<Result<Vec<(u32, &str)>, ParseError>>()?;
 
-    return Cubes(
+    return Ok(Cubes(
         get_count(&cubes, "red"),
         get_count(&cubes, "green"),
-        get_count(&cubes, "blue"));
+        get_count(&cubes, "blue")));
 }
 
-fn parse_rounds(input: &str) -> Vec<Cubes> {
+fn parse_rounds(input: &str) -> Result<Vec<Cubes>, ParseError> {
     input.split("; ")
-        .map(parse_round)
+        .map(|round| parse_round(round).map_err(|err| err.within(input, round)))
         .collect()
 }
 
 impl FromStr for Game {
-    type Err = anyhow::Error;
-
-    fn from_str(input: &str) -> Result<Self> {
-        input.split_once(": ")
-            .and_then(|(game, rounds)|
-                game.split_once(' ')
-                    .and_then(|(_, game)| game.parse::<u32>().ok())
-                    .map(|id| Game {
-                        id,
-                        rounds: parse_rounds(rounds),
-                    }))
-            .ok_or(anyhow!("Failed to parse Game from {input}"))
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, ParseError> {
+        let (game, rounds) = input.split_once(": ")
+            .ok_or_else(|| ParseError::at_end(2, input, "`: `"))?;
+
+        let id = game.strip_prefix("Game ")
+            .ok_or_else(|| ParseError::at(2, input, game, "`Game <id>`"))?;
+
+        let id = id.parse::<u32>()
+            .map_err(|_| ParseError::at(2, input, id, "game id"))?;
+
+        let rounds = parse_rounds(rounds)
+            .map_err(|err| err.within(input, rounds))?;
+
+        Ok(Game { id, rounds })
     }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::day2::game::{Cubes, Game, parse_round};
+    use crate::error::ParseError;
 
     #[test]
     pub fn test_parse_game() {
@@ -83,10 +99,28 @@ mod tests {
         "Game 1".parse::<Game>().unwrap();
     }
 
+    #[test]
+    pub fn test_parse_error() {
+        let input = "Game 1: 3 blue, 4 red; 1 red, x green";
+        let error = input.parse::<Game>().unwrap_err();
+
+        assert_eq!(error, ParseError {
+            day: 2,
+            line: 1,
+            columns: 30..31,
+            expected: "cube count".into(),
+            source: input.into(),
+        });
+
+        assert_eq!("Game one: 3 blue".parse::<Game>().unwrap_err().columns, 5..8);
+        assert_eq!("Game 1: 3 purple".parse::<Game>().unwrap_err().columns, 10..16);
+        assert_eq!("Game 1".parse::<Game>().unwrap_err().expected, "`: `");
+    }
+
     #[test]
     pub fn test_parse_round() {
-        assert_eq!(parse_round("3 blue, 4 red"), Cubes(4, 0, 3));
-        assert_eq!(parse_round("1 red, 2 green, 6 blue"), Cubes(1, 2, 6));
-        assert_eq!(parse_round("2 green"), Cubes(0, 2, 0));
+        assert_eq!(parse_round("3 blue, 4 red").unwrap(), Cubes(4, 0, 3));
+        assert_eq!(parse_round("1 red, 2 green, 6 blue").unwrap(), Cubes(1, 2, 6));
+        assert_eq!(parse_round("2 green").unwrap(), Cubes(0, 2, 0));
     }
 }
@@ -1,4 +1,7 @@
+use anyhow::Result;
+
 use crate::day2::game::{Cubes, Game};
+use crate::solution::Solution;
 
 pub mod game;
 
@@ -27,24 +30,44 @@ fn cube(colors: Cubes) -> u32 {
     colors.0 * colors.1 * colors.2
 }
 
-pub fn part1(games: &Vec<Game>) -> u32 {
-    games.iter()
-        .filter(validate(Cubes(12, 13, 14)))
-        .map(|game| game.id)
-        .sum::<u32>()
-}
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input<'a> = Vec<Game>;
+    type Answer = u32;
+
+    fn parse(input: &str) -> Result<Vec<Game>> {
+        input.lines()
+            .enumerate()
+            .map(|(number, line)| line.parse::<Game>()
+                .map_err(|err| err.at_line(number + 1).into()))
+            .collect()
+    }
 
-pub fn part2(games: &Vec<Game>) -> u32 {
-    games.iter()
-        .map(get_minimums)
-        .map(cube)
-        .sum::<u32>()
+    fn part1(games: &Vec<Game>) -> Result<u32> {
+        let result = games.iter()
+            .filter(validate(Cubes(12, 13, 14)))
+            .map(|game| game.id)
+            .sum::<u32>();
+
+        Ok(result)
+    }
+
+    fn part2(games: &Vec<Game>) -> Result<u32> {
+        let result = games.iter()
+            .map(get_minimums)
+            .map(cube)
+            .sum::<u32>();
+
+        Ok(result)
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::day2::{cube, get_minimums, part1, part2, validate};
+    use crate::day2::{cube, Day2, get_minimums, validate};
     use crate::day2::game::{Cubes, Game};
+    use crate::solution::Solution;
 
     #[test]
     pub fn test_validate() {
@@ -103,7 +126,7 @@ mod tests {
             Game { id: 5, rounds: vec![Cubes(6, 3, 1), Cubes(1, 2, 2)] },
         ];
 
-        assert_eq!(part1(&games), 8);
+        assert_eq!(Day2::part1(&games).unwrap(), 8);
     }
 
     #[test]
@@ -116,6 +139,6 @@ mod tests {
             Game { id: 5, rounds: vec![Cubes(6, 3, 1), Cubes(1, 2, 2)] },
         ];
 
-        assert_eq!(part2(&games), 2286);
+        assert_eq!(Day2::part2(&games).unwrap(), 2286);
     }
 }
@@ -1,7 +1,10 @@
+use anyhow::Result;
 use regex::Regex;
 
+use crate::solution::Solution;
+
 #[derive(Debug, Clone, PartialEq)]
-struct Object {
+pub struct Object {
     contents: String,
     left: usize,
     top: usize,
@@ -9,7 +12,7 @@ struct Object {
 
 impl Object {
     fn is_number(&self) -> bool {
-        self.contents.chars().all(|char| char.is_digit(10))
+        self.contents.chars().all(|char| char.is_ascii_digit())
     }
 
     fn is_adjacent(&self, other: &Object) -> bool {
@@ -30,41 +33,53 @@ fn get_objects(top: usize, line: &str) -> Vec<Object> {
         .collect()
 }
 
-fn parse(input: &Vec<String>) -> Vec<Object> {
-    input.iter()
+fn parse(input: &str) -> Vec<Object> {
+    input.lines()
         .enumerate()
         .flat_map(|(top, line)| get_objects(top, line))
         .collect()
 }
 
-pub fn part1(input: &Vec<String>) -> u32 {
-    let (numbers, symbols): (Vec<Object>, Vec<Object>) = parse(input).into_iter()
-        .partition(|object| object.is_number());
+pub struct Day3;
 
-    numbers.iter()
-        .filter(|number| symbols.iter().any(|symbol| number.is_adjacent(symbol)))
-        .map_while(|number| number.contents.parse::<u32>().ok())
-        .sum()
-}
+impl Solution for Day3 {
+    type Input<'a> = (Vec<Object>, Vec<Object>);
+    type Answer = u32;
+
+    fn parse(input: &str) -> Result<(Vec<Object>, Vec<Object>)> {
+        Ok(parse(input).into_iter()
+            .partition(|object| object.is_number()))
+    }
 
-pub fn part2(input: &Vec<String>) -> u32 {
-    let (numbers, symbols): (Vec<Object>, Vec<Object>) = parse(input).into_iter()
-        .partition(|object| object.is_number());
-
-    symbols.iter()
-        .map(|symbol|
-            numbers.iter()
-                .filter(|number| number.is_adjacent(symbol))
-                .map_while(|number| number.contents.parse::<u32>().ok())
-                .collect::<Vec<u32>>())
-        .filter(|gears| gears.len() == 2)
-        .map(|gears| gears.iter().product::<u32>())
-        .sum()
+    fn part1((numbers, symbols): &(Vec<Object>, Vec<Object>)) -> Result<u32> {
+        let result = numbers.iter()
+            .filter(|number| symbols.iter().any(|symbol| number.is_adjacent(symbol)))
+            .map_while(|number| number.contents.parse::<u32>().ok())
+            .sum();
+
+        Ok(result)
+    }
+
+    fn part2((numbers, symbols): &(Vec<Object>, Vec<Object>)) -> Result<u32> {
+        let result = symbols.iter()
+            .map(|symbol|
+                numbers.iter()
+                    .filter(|number| number.is_adjacent(symbol))
+                    .map_while(|number| number.contents.parse::<u32>().ok())
+                    .collect::<Vec<u32>>())
+            .filter(|gears| gears.len() == 2)
+            .map(|gears| gears.iter().product::<u32>())
+            .sum();
+
+        Ok(result)
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::day3::{get_objects, Object, parse, part1, part2};
+    use crate::day3::{Day3, get_objects, Object, parse};
+    use crate::example;
+    use crate::solution::Solution;
 
     #[test]
     pub fn test_get_objects() {
@@ -76,21 +91,9 @@ mod tests {
 
     #[test]
     pub fn test_parse() {
-        let schematic: Vec<String> = vec![
-            "467..114..".into(),
-            "...*......".into(),
-            "..35..633.".into(),
-            "......#...".into(),
-            "617*......".into(),
-            ".....+.58.".into(),
-            "..592.....".into(),
-            "......755.".into(),
-            "...$.*....".into(),
-            ".664.598..".into(),
-        ];
-
-        let objects = parse(&schematic);
-        assert_eq!(objects.iter().count(), 16);
+        let input = example::input(3, "example");
+        let objects = parse(&input);
+        assert_eq!(objects.len(), 16);
         assert_eq!(objects.first(), Some(Object { contents: "467".into(), left: 0, top: 0 }).as_ref());
         assert_eq!(objects.last(), Some(Object { contents: "598".into(), left: 5, top: 9 }).as_ref());
         assert_eq!(objects.contains(&Object { contents: "$".into(), left: 3, top: 8 }), true);
@@ -121,37 +124,13 @@ mod tests {
 
     #[test]
     pub fn test_part_1() {
-        let schematic = vec![
-            "467..114..".into(),
-            "...*......".into(),
-            "..35..633.".into(),
-            "......#...".into(),
-            "617*......".into(),
-            ".....+.58.".into(),
-            "..592.....".into(),
-            "......755.".into(),
-            "...$.*....".into(),
-            ".664.598..".into(),
-        ];
-
-        assert_eq!(part1(&schematic), 4361);
+        let input = example::input(3, "example");
+        assert_eq!(Day3::part1(&Day3::parse(&input).unwrap()).unwrap(), 4361);
     }
 
     #[test]
     pub fn test_part_2() {
-        let schematic = vec![
-            "467..114..".into(),
-            "...*......".into(),
-            "..35..633.".into(),
-            "......#...".into(),
-            "617*......".into(),
-            ".....+.58.".into(),
-            "..592.....".into(),
-            "......755.".into(),
-            "...$.*....".into(),
-            ".664.598..".into(),
-        ];
-
-        assert_eq!(part2(&schematic), 467835);
+        let input = example::input(3, "example");
+        assert_eq!(Day3::part2(&Day3::parse(&input).unwrap()).unwrap(), 467835);
     }
 }
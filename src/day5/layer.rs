@@ -0,0 +1,192 @@
+use std::fmt::{Display, Formatter};
+use std::ops::Range;
+use std::str::FromStr;
+
+use itertools::Itertools;
+
+use crate::day5::mapping::Mapping;
+use crate::error::ParseError;
+
+fn reach(mappings: &[Mapping], index: &[usize], range: fn(&Mapping) -> &Range<usize>) -> Vec<usize> {
+    index.iter()
+        .scan(0, |end, i| {
+            *end = range(&mappings[*i]).end.max(*end);
+            Some(*end)
+        })
+        .collect()
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MappingLayer {
+    mappings: Vec<Mapping>,
+    forward: Vec<usize>,
+    reverse: Vec<usize>,
+    forward_reach: Vec<usize>,
+    reverse_reach: Vec<usize>,
+}
+
+impl MappingLayer {
+    pub fn new(mappings: Vec<Mapping>) -> MappingLayer {
+        let forward = (0..mappings.len()).sorted_by_key(|i| mappings[*i].source.start).collect::<Vec<usize>>();
+        let reverse = (0..mappings.len()).sorted_by_key(|i| mappings[*i].destination.start).collect::<Vec<usize>>();
+
+        MappingLayer {
+            forward_reach: reach(&mappings, &forward, |mapping| &mapping.source),
+            reverse_reach: reach(&mappings, &reverse, |mapping| &mapping.destination),
+            mappings,
+            forward,
+            reverse,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.mappings.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.mappings.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item=&Mapping> {
+        self.mappings.iter()
+    }
+
+    pub fn sorted(&self) -> impl Iterator<Item=&Mapping> {
+        self.forward.iter().map(|index| &self.mappings[*index])
+    }
+
+    fn find(&self, index: &[usize], reach: &[usize], id: usize, range: fn(&Mapping) -> &Range<usize>) -> Option<&Mapping> {
+        let position = reach.partition_point(|end| *end <= id);
+
+        index.get(position)
+            .map(|i| &self.mappings[*i])
+            .filter(|mapping| range(mapping).contains(&id))
+    }
+
+    pub fn forward(&self, id: usize) -> usize {
+        self.find(&self.forward, &self.forward_reach, id, |mapping| &mapping.source)
+            .map_or(id, |mapping| mapping.destination.start + id - mapping.source.start)
+    }
+
+    pub fn reverse(&self, id: usize) -> usize {
+        self.find(&self.reverse, &self.reverse_reach, id, |mapping| &mapping.destination)
+            .map_or(id, |mapping| mapping.source.start + id - mapping.destination.start)
+    }
+
+    pub fn inverse(&self) -> MappingLayer {
+        MappingLayer {
+            mappings: self.mappings.iter().map(Mapping::invert).collect(),
+            forward: self.reverse.clone(),
+            reverse: self.forward.clone(),
+            forward_reach: self.reverse_reach.clone(),
+            reverse_reach: self.forward_reach.clone(),
+        }
+    }
+
+    pub fn gaps(&self) -> impl Iterator<Item=Range<usize>> + '_ {
+        self.sorted()
+            .filter(|mapping| !mapping.source.is_empty())
+            .scan(None, |end: &mut Option<usize>, mapping| {
+                let gap = end.filter(|end| *end < mapping.source.start)
+                    .map(|end| end..mapping.source.start);
+
+                *end = Some(end.map_or(mapping.source.end, |end| end.max(mapping.source.end)));
+                Some(gap)
+            })
+            .flatten()
+    }
+}
+
+impl FromIterator<Mapping> for MappingLayer {
+    fn from_iter<T: IntoIterator<Item=Mapping>>(mappings: T) -> Self {
+        MappingLayer::new(mappings.into_iter().collect())
+    }
+}
+
+impl Display for MappingLayer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for mapping in &self.mappings {
+            writeln!(f, "{mapping}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for MappingLayer {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, ParseError> {
+        input.lines()
+            .enumerate()
+            .map(|(number, line)| line.parse::<Mapping>()
+                .map_err(|err| err.at_line(number + 1)))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::day5::mapping::Mapping;
+
+    use super::MappingLayer;
+
+    const LAYER: &str = "0 15 37
+37 52 2
+39 0 15
+";
+
+    #[test]
+    fn test_parse() {
+        let layer = LAYER.parse::<MappingLayer>().unwrap();
+
+        assert_eq!(layer.len(), 3);
+        assert_eq!(layer.iter().next(), Some(&Mapping { source: 15..52, destination: 0..37 }));
+        assert_eq!(layer.sorted().map(|mapping| mapping.source.start).collect::<Vec<usize>>(), vec![0, 15, 52]);
+        assert_eq!("0 15 37\n3x 52 2".parse::<MappingLayer>().unwrap_err().line, 2);
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(LAYER.parse::<MappingLayer>().unwrap().to_string(), LAYER);
+    }
+
+    #[test]
+    fn test_forward() {
+        let layer = LAYER.parse::<MappingLayer>().unwrap();
+
+        assert_eq!(layer.forward(0), 39);
+        assert_eq!(layer.forward(14), 53);
+        assert_eq!(layer.forward(15), 0);
+        assert_eq!(layer.forward(53), 38);
+        assert_eq!(layer.forward(54), 54);
+
+        let layer = "100 0 50\n200 10 5\n300 60 5\n400 40 30".parse::<MappingLayer>().unwrap();
+        assert_eq!(layer.forward(12), 112);
+        assert_eq!(layer.forward(30), 130);
+        assert_eq!(layer.forward(55), 415);
+        assert_eq!(layer.forward(62), 422);
+        assert_eq!(layer.forward(70), 70);
+
+        let layer = "200 10 5\n100 0 50".parse::<MappingLayer>().unwrap();
+        assert_eq!(layer.forward(12), 112);
+    }
+
+    #[test]
+    fn test_reverse() {
+        let layer = LAYER.parse::<MappingLayer>().unwrap();
+
+        for id in 0..60 {
+            assert_eq!(layer.reverse(layer.forward(id)), id);
+            assert_eq!(layer.inverse().forward(id), layer.reverse(id));
+        }
+    }
+
+    #[test]
+    fn test_gaps() {
+        let layer = "50 98 2\n52 50 40\n0 95 1".parse::<MappingLayer>().unwrap();
+        assert_eq!(layer.gaps().collect::<Vec<_>>(), vec![90..95, 96..98]);
+
+        assert_eq!(LAYER.parse::<MappingLayer>().unwrap().gaps().count(), 0);
+    }
+}
@@ -1,129 +1,314 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fmt::{Display, Formatter};
 use std::ops::Range;
 use std::str::FromStr;
 
-use anyhow::{anyhow, Context};
-use anyhow::Result;
+use anyhow::{bail, Result};
 use itertools::Itertools;
 use regex::Regex;
 
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
-#[allow(dead_code)]
-pub enum MappingType {
-    Seeds = 0,
-    Soil = 1,
-    Fertilizer = 2,
-    Water = 3,
-    Light = 4,
-    Temperature = 5,
-    Humidity = 6,
-    Location = 7,
-}
+use crate::day5::layer::MappingLayer;
+use crate::error::ParseError;
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Mapping {
     pub source: Range<usize>,
     pub destination: Range<usize>,
 }
 
+impl Mapping {
+    fn identity(range: Range<usize>) -> Mapping {
+        Mapping { source: range.clone(), destination: range }
+    }
+
+    fn is_identity(&self) -> bool {
+        self.source.start == self.destination.start
+    }
+
+    pub fn invert(&self) -> Mapping {
+        Mapping { source: self.destination.clone(), destination: self.source.clone() }
+    }
+}
+
+impl Display for Mapping {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {} {}", self.destination.start, self.source.start, self.source.len())
+    }
+}
+
 impl FromStr for Mapping {
-    type Err = anyhow::Error;
+    type Err = ParseError;
 
-    fn from_str(input: &str) -> Result<Self> {
-        Regex::new(r"(?<dest>\d+)\s(?<src>\d+)\s(?<len>\d+)")?
+    fn from_str(input: &str) -> Result<Self, ParseError> {
+        let captures = Regex::new(r"^(?<dest>\d+)\s+(?<src>\d+)\s+(?<len>\d+)$")
+            .expect("Mapping pattern is valid")
             .captures(input)
-            .context("Failed to find mapping values in {input}")
-            .and_then(|captures| Ok([
-                captures["src"].parse::<usize>()?,
-                captures["dest"].parse::<usize>()?,
-                captures["len"].parse::<usize>()?
-            ]))
-            .map(|[src, dest, len]| Mapping {
-                source: src..src + len,
-                destination: dest..dest + len,
-            })
+            .ok_or_else(|| ParseError::new(5, input, 0..input.len(), "`<destination> <source> <length>`"))?;
+
+        let [src, dest, len] = ["src", "dest", "len"].map(|name| {
+            let value = captures.name(name).expect("Mapping pattern captures every value");
+
+            value.as_str().parse::<usize>()
+                .map_err(|_| ParseError::new(5, input, value.range(), "a number that fits in usize"))
+        });
+
+        let [src, dest, len] = [src?, dest?, len?];
+
+        let (Some(src_end), Some(dest_end)) = (src.checked_add(len), dest.checked_add(len)) else {
+            let len = captures.name("len").expect("Mapping pattern captures every value");
+            return Err(ParseError::new(5, input, len.range(), "a length that keeps both ranges within usize"));
+        };
+
+        Ok(Mapping {
+            source: src..src_end,
+            destination: dest..dest_end,
+        })
     }
 }
 
-pub fn parse(input: &Vec<String>) -> Result<(Vec<usize>, Vec<Vec<Mapping>>)> {
-    let (seeds, maps) = input.split_at(1);
-    let mapping = Regex::new(r"\d+\s\d+\s\d+")?;
+pub fn merge_ranges(mut ranges: Vec<Range<usize>>) -> Vec<Range<usize>> {
+    ranges.retain(|range| !range.is_empty());
+    ranges.sort_by_key(|range| range.start);
 
-    let seeds: Vec<usize> = seeds[0]
-        .strip_prefix("seeds: ").context("Failed to remove seeds prefix from {seeds}")?
-        .split_ascii_whitespace()
-        .map(|id| id.parse::<usize>().map_err(|err| anyhow!(err)))
-        .collect::<Result<Vec<usize>>>()?;
+    ranges.into_iter().fold(Vec::new(), |mut merged: Vec<Range<usize>>, range| {
+        match merged.last_mut() {
+            Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+            _ => merged.push(range),
+        }
+
+        merged
+    })
+}
+
+fn split(layer: &MappingLayer, range: &Range<usize>) -> Vec<Mapping> {
+    let mut pieces = Vec::new();
+    let mut start = range.start;
+
+    for mapping in layer.sorted().filter(|mapping| mapping.source.end > range.start && mapping.source.start < range.end) {
+        if start < mapping.source.start {
+            pieces.push(Mapping::identity(start..mapping.source.start));
+        }
+
+        let overlap = start.max(mapping.source.start)..range.end.min(mapping.source.end);
+
+        if !overlap.is_empty() {
+            let destination = mapping.destination.start + overlap.start - mapping.source.start;
+
+            pieces.push(Mapping {
+                destination: destination..destination + overlap.len(),
+                source: overlap.clone(),
+            });
+        }
+
+        start = start.max(overlap.end);
+    }
+
+    if start < range.end {
+        pieces.push(Mapping::identity(start..range.end));
+    }
 
-    let maps = maps.iter()
-        .group_by(|line| mapping.is_match(line))
-        .into_iter()
-        .filter(|(key, _)| *key)
-        .map(|(_, group)|
-            group.into_iter()
-                .map(|line| line.parse::<Mapping>())
-                .collect())
-        .collect::<Result<Vec<Vec<Mapping>>>>()?;
+    pieces
+}
 
-    Ok((seeds, maps))
+pub fn map_ranges(layer: &MappingLayer, ranges: &[Range<usize>]) -> Vec<Range<usize>> {
+    merge_ranges(ranges.iter()
+        .flat_map(|range| split(layer, range))
+        .map(|piece| piece.destination)
+        .collect())
 }
 
-#[cfg(test)]
-mod tests {
-    use super::{Mapping, parse};
+pub fn compose(first: &MappingLayer, second: &MappingLayer) -> MappingLayer {
+    let domain = merge_ranges(first.iter()
+        .chain(second.iter())
+        .map(|mapping| mapping.source.clone())
+        .collect());
 
-    #[test]
-    fn test_parse() {
-        let input = "seeds: 79 14 55 13
+    let pieces = domain.iter()
+        .flat_map(|range| split(first, range))
+        .flat_map(|outer| split(second, &outer.destination).into_iter()
+            .map(move |inner| {
+                let source = outer.source.start + inner.source.start - outer.destination.start;
 
-seed-to-soil map:
-50 98 2
-52 50 48
+                Mapping {
+                    source: source..source + inner.source.len(),
+                    destination: inner.destination,
+                }
+            }))
+        .filter(|piece| !piece.is_identity())
+        .sorted_by_key(|piece| piece.source.start);
+
+    pieces.fold(Vec::new(), |mut fused: Vec<Mapping>, piece| {
+        match fused.last_mut() {
+            Some(last) if last.source.end == piece.source.start && last.destination.end == piece.destination.start => {
+                last.source.end = piece.source.end;
+                last.destination.end = piece.destination.end;
+            }
+            _ => fused.push(piece),
+        }
+
+        fused
+    }).into_iter().collect()
+}
+
+pub fn compose_all(layers: &[MappingLayer]) -> MappingLayer {
+    layers.iter().fold(MappingLayer::default(), |fused, layer| compose(&fused, layer))
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Section<'a> {
+    pub from: &'a str,
+    pub to: &'a str,
+    pub line: usize,
+    pub mappings: MappingLayer,
+}
+
+impl Section<'_> {
+    pub fn line_of(&self, index: usize) -> usize {
+        self.line + 1 + index
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Almanac<'a> {
+    pub seeds: Vec<usize>,
+    pub sections: Vec<Section<'a>>,
+}
+
+impl Almanac<'_> {
+    pub fn chain(&self, from: &str, to: &str) -> Result<Vec<MappingLayer>> {
+        let mut previous: HashMap<&str, (usize, bool)> = HashMap::new();
+        let mut seen = HashSet::from([from]);
+        let mut queue = VecDeque::from([from]);
+
+        while let Some(category) = queue.pop_front() {
+            if category == to {
+                break;
+            }
+
+            for (index, section) in self.sections.iter().enumerate() {
+                let (next, forward) = if section.from == category {
+                    (section.to, true)
+                } else if section.to == category {
+                    (section.from, false)
+                } else {
+                    continue;
+                };
+
+                if seen.insert(next) {
+                    previous.insert(next, (index, forward));
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        if !seen.contains(to) {
+            bail!("No mappings lead from {from} to {to}");
+        }
+
+        let mut chain = Vec::new();
+        let mut category = to;
+
+        while let Some((index, forward)) = previous.get(category).copied() {
+            let section = &self.sections[index];
 
-soil-to-fertilizer map:
-0 15 37
-37 52 2
-39 0 15
+            if forward {
+                chain.push(section.mappings.clone());
+                category = section.from;
+            } else {
+                chain.push(section.mappings.inverse());
+                category = section.to;
+            }
+        }
 
-fertilizer-to-water map:
-49 53 8
-0 11 42
-42 0 7
-57 7 4
+        chain.reverse();
+        Ok(chain)
+    }
+}
+
+fn parse_seeds(line: &str) -> Result<Vec<usize>, ParseError> {
+    line.strip_prefix("seeds: ")
+        .ok_or_else(|| ParseError::new(5, line, 0..0, "`seeds: `"))?
+        .split_ascii_whitespace()
+        .map(|id| id.parse::<usize>()
+            .map_err(|_| ParseError::at(5, line, id, "seed number")))
+        .collect()
+}
+
+fn parse_header(line: &str) -> Result<(&str, &str), ParseError> {
+    line.strip_suffix(" map:")
+        .and_then(|categories| categories.split_once("-to-"))
+        .filter(|(from, to)| !from.is_empty() && !to.is_empty())
+        .ok_or_else(|| ParseError::new(5, line, 0..line.len(), "`<from>-to-<to> map:`"))
+}
+
+pub fn parse(input: &str) -> Result<Almanac<'_>> {
+    let mut lines = input.lines().enumerate();
 
-water-to-light map:
-88 18 7
-18 25 70
+    let seeds = match lines.next() {
+        Some((_, line)) => parse_seeds(line)?,
+        None => Err(ParseError::at_end(5, input, "`seeds: `"))?,
+    };
 
-light-to-temperature map:
-45 77 23
-81 45 19
-68 64 13
+    let mut sections: Vec<(&str, &str, usize, Vec<Mapping>)> = Vec::new();
+    let mut open = false;
 
-temperature-to-humidity map:
-0 69 1
-1 0 69
+    for (number, line) in lines {
+        if line.is_empty() {
+            open = false;
+            continue;
+        }
 
-humidity-to-location map:
-60 56 37
-56 93 4".lines()
-            .map(String::from)
-            .collect::<Vec<String>>();
+        if !line.starts_with(|char: char| char.is_ascii_digit()) {
+            let (from, to) = parse_header(line).map_err(|err| err.at_line(number + 1))?;
+            sections.push((from, to, number + 1, Vec::new()));
+            open = true;
+            continue;
+        }
 
-        let (seeds, mappings) = parse(&input).unwrap();
+        let mapping = line.parse::<Mapping>().map_err(|err| err.at_line(number + 1))?;
+
+        match sections.last_mut() {
+            Some((_, _, _, mappings)) if open => mappings.push(mapping),
+            _ => Err(ParseError::new(5, line, 0..0, "`<from>-to-<to> map:`").at_line(number + 1))?,
+        }
+    }
+
+    let sections = sections.into_iter()
+        .map(|(from, to, line, mappings)| Section { from, to, line, mappings: MappingLayer::new(mappings) })
+        .collect();
+
+    Ok(Almanac { seeds, sections })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::day5::layer::MappingLayer;
+    use crate::error::ParseError;
+    use crate::example;
+
+    use super::{Almanac, compose, compose_all, map_ranges, Mapping, merge_ranges, parse, Section};
+
+    #[test]
+    fn test_parse() {
+        let input = example::input(5, "example");
+        let Almanac { seeds, sections } = parse(&input).unwrap();
 
         assert_eq!(seeds, vec![79, 14, 55, 13]);
-        assert_eq!(mappings.len(), 7);
+        assert_eq!(sections.len(), 7);
+        assert_eq!((sections[0].from, sections[0].to), ("seed", "soil"));
+        assert_eq!((sections[6].from, sections[6].to), ("humidity", "location"));
+        assert_eq!((sections[1].line, sections[1].line_of(2)), (7, 10));
 
-        assert_eq!(*mappings.get(0).unwrap(), vec![
+        assert_eq!(sections[0].mappings, MappingLayer::new(vec![
             Mapping {
                 source: 98..100,
                 destination: 50..52,
             }, Mapping {
                 source: 50..98,
                 destination: 52..100,
-            }]);
+            }]));
 
-        assert_eq!(*mappings.get(1).unwrap(), vec![
+        assert_eq!(sections[1].mappings, MappingLayer::new(vec![
             Mapping {
                 source: 15..52,
                 destination: 0..37,
@@ -133,7 +318,116 @@ humidity-to-location map:
             }, Mapping {
                 source: 0..15,
                 destination: 39..54,
-            }]);
+            }]));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = parse("seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 4B").unwrap_err();
+
+        assert_eq!(error.downcast::<ParseError>().unwrap(), ParseError {
+            day: 5,
+            line: 5,
+            columns: 0..8,
+            expected: "`<destination> <source> <length>`".into(),
+            source: "52 50 4B".into(),
+        });
+
+        let line = format!("50 {} 10", usize::MAX - 5);
+        let error = parse(&format!("seeds: 79\n\nseed-to-soil map:\n{line}")).unwrap_err().downcast::<ParseError>().unwrap();
+        assert_eq!((error.line, error.columns.clone()), (4, line.len() - 2..line.len()));
+        assert_eq!(error.expected, "a length that keeps both ranges within usize");
+
+        let error = parse("seeds: 79 x4").unwrap_err().downcast::<ParseError>().unwrap();
+        assert_eq!((error.line, error.columns), (1, 10..12));
+
+        let error = parse("seeds: 79\n\nseed-to-soil:\n50 98 2").unwrap_err().downcast::<ParseError>().unwrap();
+        assert_eq!((error.line, error.expected.as_str()), (3, "`<from>-to-<to> map:`"));
+
+        let error = parse("seeds: 79\n\n50 98 2").unwrap_err().downcast::<ParseError>().unwrap();
+        assert_eq!((error.line, error.columns), (3, 0..0));
+
+        let error = parse("seeds: 79\n\nseed-to-soil map:\n50 98 2\n\n52 50 48").unwrap_err().downcast::<ParseError>().unwrap();
+        assert_eq!((error.line, error.columns), (6, 0..0));
+    }
+
+    #[test]
+    fn test_chain() {
+        let almanac = Almanac {
+            seeds: vec![],
+            sections: vec![
+                Section { from: "b", to: "c", line: 1, mappings: "10 0 5".parse().unwrap() },
+                Section { from: "a", to: "b", line: 4, mappings: "0 20 5".parse().unwrap() },
+                Section { from: "d", to: "c", line: 7, mappings: "10 30 5".parse().unwrap() },
+            ],
+        };
+
+        assert_eq!(almanac.chain("a", "c").unwrap(), vec![
+            MappingLayer::new(vec![Mapping { source: 20..25, destination: 0..5 }]),
+            MappingLayer::new(vec![Mapping { source: 0..5, destination: 10..15 }]),
+        ]);
+
+        assert_eq!(almanac.chain("c", "d").unwrap(), vec![
+            MappingLayer::new(vec![Mapping { source: 10..15, destination: 30..35 }]),
+        ]);
+
+        assert!(almanac.chain("a", "a").unwrap().is_empty());
+        assert_eq!(almanac.chain("a", "e").unwrap_err().to_string(), "No mappings lead from a to e");
+    }
+
+    #[test]
+    fn test_merge_ranges() {
+        assert_eq!(merge_ranges(vec![10..20, 0..5, 5..7, 15..30, 40..40, 35..36]), vec![0..7, 10..30, 35..36]);
+        assert!(merge_ranges(vec![]).is_empty());
+    }
+
+    #[test]
+    #[allow(clippy::single_range_in_vec_init)]
+    fn test_map_ranges() {
+        let layer = "50 98 2\n52 50 48".parse::<MappingLayer>().unwrap();
+
+        assert_eq!(map_ranges(&layer, &[79..93]), vec![81..95]);
+        assert_eq!(map_ranges(&layer, &[0..10]), vec![0..10]);
+        assert_eq!(map_ranges(&layer, &[40..60]), vec![40..50, 52..62]);
+        assert_eq!(map_ranges(&layer, &[95..105]), vec![50..52, 97..105]);
+    }
+
+    #[test]
+    fn test_compose() {
+        let first = "50 98 2\n52 50 48".parse::<MappingLayer>().unwrap();
+        let second = "0 15 37\n37 52 2\n39 0 15".parse::<MappingLayer>().unwrap();
+
+        assert_eq!(compose(&first, &second), MappingLayer::new(vec![
+            Mapping { source: 0..15, destination: 39..54 },
+            Mapping { source: 15..50, destination: 0..35 },
+            Mapping { source: 50..52, destination: 37..39 },
+            Mapping { source: 52..98, destination: 54..100 },
+            Mapping { source: 98..100, destination: 35..37 },
+        ]));
+
+        assert_eq!(compose(&MappingLayer::default(), &first).iter().collect::<Vec<&Mapping>>(), first.sorted().collect::<Vec<&Mapping>>());
+    }
+
+    #[test]
+    fn test_compose_all() {
+        let layers = vec![
+            MappingLayer::new(vec![Mapping { source: 0..10, destination: 10..20 }]),
+            MappingLayer::new(vec![Mapping { source: 10..20, destination: 0..10 }]),
+        ];
+
+        assert_eq!(compose_all(&layers), MappingLayer::new(vec![Mapping { source: 10..20, destination: 0..10 }]));
+        assert_eq!(compose_all(&layers[..1]), layers[0]);
+        assert!(compose_all(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_display_mapping() {
+        let mapping = "50 98 2".parse::<Mapping>().unwrap();
+        assert_eq!(mapping.to_string(), "50 98 2");
     }
 
     #[test]
@@ -3,128 +3,195 @@ use std::ops::Range;
 use anyhow::{Context, Result};
 use itertools::Itertools;
 
-use crate::day5::mapping::{Mapping, MappingType, parse};
-use crate::day5::mapping::MappingType::{Location, Seeds};
-
-mod mapping;
-
-fn map(maps: &Vec<Vec<Mapping>>, id: usize, from: MappingType, to: MappingType) -> usize {
-    if from > to {
-        maps[to as usize..from as usize].iter().rev().fold(id, |id, maps|
-            maps.iter()
-                .find(|map| map.destination.contains(&id))
-                .map(|map| map.source.start + id - map.destination.start)
-                .unwrap_or(id))
-    } else {
-        maps[from as usize..to as usize].iter().fold(id, |id, maps|
-            maps.iter()
-                .find(|map| map.source.contains(&id))
-                .map(|map| map.destination.start + id - map.source.start)
-                .unwrap_or(id))
-    }
+use crate::day5::layer::MappingLayer;
+use crate::day5::mapping::{Almanac, compose_all, merge_ranges, parse};
+use crate::solution::Solution;
+
+pub mod layer;
+pub mod mapping;
+pub mod validate;
+
+pub fn map(almanac: &Almanac, id: usize, from: &str, to: &str) -> Result<usize> {
+    let chain = almanac.chain(from, to)?;
+
+    Ok(chain.iter().fold(id, |id, layer| layer.forward(id)))
 }
 
-pub fn part1(input: &Vec<String>) -> Result<usize> {
-    let (seeds, maps) = parse(input)?;
+pub fn fuse(almanac: &Almanac, from: &str, to: &str) -> Result<MappingLayer> {
+    Ok(compose_all(&almanac.chain(from, to)?))
+}
 
-    seeds.iter()
-        .map(|seed| map(&maps, *seed, Seeds, Location))
-        .min()
-        .context("Failed to find any locations for {seeds}")
+fn map_ranges(almanac: &Almanac, ranges: Vec<Range<usize>>, from: &str, to: &str) -> Result<Vec<Range<usize>>> {
+    Ok(almanac.chain(from, to)?.iter()
+        .fold(merge_ranges(ranges), |ranges, layer| mapping::map_ranges(layer, &ranges)))
 }
 
-pub fn part2(input: &Vec<String>) -> Result<usize> {
-    let (seeds, maps) = parse(input)?;
+pub struct Day5;
 
-    let ranges = seeds.into_iter().tuples()
-        .map(|(start, len)| start..start + len)
-        .collect::<Vec<Range<usize>>>();
+impl Solution for Day5 {
+    type Input<'a> = Almanac<'a>;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Almanac<'_>> {
+        parse(input)
+    }
+
+    fn part1(almanac: &Almanac) -> Result<usize> {
+        let fused = fuse(almanac, "seed", "location")?;
+
+        almanac.seeds.iter()
+            .map(|seed| fused.forward(*seed))
+            .min()
+            .with_context(|| format!("Failed to find any locations for {:?}", almanac.seeds))
+    }
 
-    (1..)
-        .map(|location| (location, map(&maps, location, Location, Seeds)))
-        .find(|(_, seed)| ranges.iter().any(|range| range.contains(seed)))
-        .map(|(location, _)| location)
-        .context("Failed to find nearest location for {seeds}")
+    fn part2(almanac: &Almanac) -> Result<usize> {
+        let ranges = almanac.seeds.iter().tuples()
+            .map(|(start, len)| start.checked_add(*len)
+                .map(|end| *start..end)
+                .with_context(|| format!("Seed range {start} +{len} does not fit in usize")))
+            .collect::<Result<Vec<Range<usize>>>>()?;
+
+        map_ranges(almanac, ranges, "seed", "location")?.first()
+            .map(|range| range.start)
+            .with_context(|| format!("Failed to find nearest location for {:?}", almanac.seeds))
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{map, parse, part1, part2};
-    use super::MappingType::{Fertilizer, Humidity, Light, Location, Seeds, Soil, Temperature, Water};
+    use itertools::Itertools;
+
+    use crate::example;
+    use crate::solution::Solution;
+
+    use super::{Day5, fuse, map, map_ranges, parse};
+
+    #[test]
+    fn test_map() {
+        let input = example::input(5, "example");
+        let almanac = parse(&input).unwrap();
+
+        assert_eq!(map(&almanac, 79, "seed", "soil").unwrap(), 81);
+        assert_eq!(map(&almanac, 79, "seed", "fertilizer").unwrap(), 81);
+        assert_eq!(map(&almanac, 79, "seed", "water").unwrap(), 81);
+        assert_eq!(map(&almanac, 79, "seed", "light").unwrap(), 74);
+        assert_eq!(map(&almanac, 79, "seed", "temperature").unwrap(), 78);
+        assert_eq!(map(&almanac, 79, "seed", "humidity").unwrap(), 78);
+        assert_eq!(map(&almanac, 79, "seed", "location").unwrap(), 82);
+
+        assert_eq!(map(&almanac, 14, "seed", "location").unwrap(), 43);
+        assert_eq!(map(&almanac, 55, "seed", "location").unwrap(), 86);
+        assert_eq!(map(&almanac, 13, "seed", "location").unwrap(), 35);
+
+        assert_eq!(map(&almanac, 35, "location", "seed").unwrap(), 13);
+    }
+
+    #[test]
+    fn test_fuse_matches_map() {
+        let input = example::input(5, "example");
+        let almanac = parse(&input).unwrap();
+
+        for (from, to) in [("seed", "location"), ("seed", "soil"), ("soil", "light"), ("water", "humidity")] {
+            let fused = fuse(&almanac, from, to).unwrap();
 
-    const INPUT: &str = "seeds: 79 14 55 13
+            assert!(fused.sorted().tuple_windows().all(|(a, b)| a.source.end <= b.source.start));
 
-seed-to-soil map:
-50 98 2
-52 50 48
+            for id in 0..120 {
+                assert_eq!(fused.forward(id), map(&almanac, id, from, to).unwrap());
+            }
+        }
+    }
+
+    #[test]
+    #[allow(clippy::single_range_in_vec_init)]
+    fn test_map_ranges() {
+        let input = example::input(5, "example");
+        let almanac = parse(&input).unwrap();
+
+        assert_eq!(map_ranges(&almanac, vec![79..80], "seed", "location").unwrap(), vec![82..83]);
+        assert_eq!(map_ranges(&almanac, vec![82..83], "seed", "soil").unwrap(), vec![84..85]);
+        assert_eq!(map_ranges(&almanac, vec![79..93, 55..68], "seed", "location").unwrap().first().unwrap().start, 46);
+    }
 
-soil-to-fertilizer map:
-0 15 37
-37 52 2
-39 0 15
+    #[test]
+    fn test_map_ranges_matches_map() {
+        let input = example::input(5, "example");
+        let almanac = parse(&input).unwrap();
+
+        for range in [0..120, 40..60, 90..110, 13..14] {
+            let expected = range.clone().map(|seed| map(&almanac, seed, "seed", "location").unwrap()).min().unwrap();
+            assert_eq!(map_ranges(&almanac, vec![range], "seed", "location").unwrap().first().unwrap().start, expected);
+        }
+    }
 
-fertilizer-to-water map:
-49 53 8
-0 11 42
-42 0 7
-57 7 4
+    #[test]
+    fn test_overlapping_layer() {
+        let input = "seeds: 30\n\nseed-to-soil map:\n100 0 50\n200 10 5\n400 40 30\n\nsoil-to-location map:\n0 110 10";
+        let almanac = parse(input).unwrap();
+        let fused = fuse(&almanac, "seed", "location").unwrap();
 
-water-to-light map:
-88 18 7
-18 25 70
+        for id in 0..80 {
+            assert_eq!(map(&almanac, id, "seed", "location").unwrap(), fused.forward(id));
+        }
 
-light-to-temperature map:
-45 77 23
-81 45 19
-68 64 13
+        assert_eq!(Day5::part1(&almanac).unwrap(), 130);
 
-temperature-to-humidity map:
-0 69 1
-1 0 69
+        let input = "seeds: 12\n\nseed-to-soil map:\n200 10 5\n100 0 50\n\nsoil-to-location map:\n0 110 10";
+        let almanac = parse(input).unwrap();
 
-humidity-to-location map:
-60 56 37
-56 93 4
-";
+        assert_eq!(map(&almanac, 12, "seed", "location").unwrap(), 2);
+        assert_eq!(Day5::part1(&almanac).unwrap(), 2);
+    }
 
     #[test]
-    fn test_map() {
-        let input = INPUT.lines()
-            .map(String::from)
-            .collect::<Vec<String>>();
+    fn test_part2_large_ranges() {
+        let original = example::input(5, "example");
 
-        let (_, maps) = parse(&input).unwrap();
+        let input = original.replacen("seeds: 79 14 55 13", "seeds: 0 1000000000000000 79 14", 1);
+        assert_eq!(Day5::part2(&Day5::parse(&input).unwrap()).unwrap(), 0);
 
-        assert_eq!(map(&maps, 79, Seeds, Soil), 81);
-        assert_eq!(map(&maps, 79, Seeds, Fertilizer), 81);
-        assert_eq!(map(&maps, 79, Seeds, Water), 81);
-        assert_eq!(map(&maps, 79, Seeds, Light), 74);
-        assert_eq!(map(&maps, 79, Seeds, Temperature), 78);
-        assert_eq!(map(&maps, 79, Seeds, Humidity), 78);
-        assert_eq!(map(&maps, 79, Seeds, Location), 82);
+        let input = original.replacen("seeds: 79 14 55 13", "seeds: 82 1000000000000 14 1", 1);
+        assert_eq!(Day5::part2(&Day5::parse(&input).unwrap()).unwrap(), 19);
 
-        assert_eq!(map(&maps, 14, Seeds, Location), 43);
-        assert_eq!(map(&maps, 55, Seeds, Location), 86);
-        assert_eq!(map(&maps, 13, Seeds, Location), 35);
+        let start = usize::MAX - 5;
+        let input = original.replacen("seeds: 79 14 55 13", &format!("seeds: {start} 10"), 1);
+        let error = Day5::part2(&Day5::parse(&input).unwrap()).unwrap_err();
+        assert_eq!(error.to_string(), format!("Seed range {start} +10 does not fit in usize"));
+    }
 
-        assert_eq!(map(&maps, 35, Location, Seeds), 13);
+    #[test]
+    fn test_reordered_almanac() {
+        let input = example::input(5, "example");
+        let sections = input.split("\n\n").collect::<Vec<&str>>();
+        let input = [sections[0]].into_iter().chain(sections[1..].iter().rev().copied()).join("\n\n");
+
+        assert_eq!(Day5::part1(&Day5::parse(&input).unwrap()).unwrap(), 35);
+        assert_eq!(Day5::part2(&Day5::parse(&input).unwrap()).unwrap(), 46);
     }
 
     #[test]
-    fn test_part1() {
-        let input = INPUT.lines()
-            .map(String::from)
-            .collect::<Vec<String>>();
+    fn test_extended_almanac() {
+        let input = example::input(5, "example");
+        let input = format!("{input}\nlocation-to-region map:\n0 80 10\n\ncolor-to-seed map:\n79 0 1\n");
+        let almanac = parse(&input).unwrap();
+
+        assert_eq!(map(&almanac, 79, "seed", "region").unwrap(), 2);
+        assert_eq!(map(&almanac, 0, "color", "location").unwrap(), 82);
+        assert_eq!(map(&almanac, 2, "region", "color").unwrap(), 0);
+        assert_eq!(map(&almanac, 81, "soil", "region").unwrap(), 2);
+        assert_eq!(map(&almanac, 0, "seed", "moon").unwrap_err().to_string(), "No mappings lead from seed to moon");
+    }
 
-        assert_eq!(part1(&input).unwrap(), 35);
+    #[test]
+    fn test_part1() {
+        let input = example::input(5, "example");
+        assert_eq!(Day5::part1(&Day5::parse(&input).unwrap()).unwrap(), 35);
     }
 
     #[test]
     fn test_part2() {
-        let input = INPUT.lines()
-            .map(String::from)
-            .collect::<Vec<String>>();
-
-        assert_eq!(part2(&input).unwrap(), 46);
+        let input = example::input(5, "example");
+        assert_eq!(Day5::part2(&Day5::parse(&input).unwrap()).unwrap(), 46);
     }
 }
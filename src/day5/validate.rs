@@ -0,0 +1,140 @@
+use std::fmt::{Display, Formatter};
+use std::ops::Range;
+
+use itertools::Itertools;
+
+use crate::day5::mapping::{Almanac, Mapping, Section};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Finding {
+    OverlappingSources { line: usize, other: usize, range: Range<usize> },
+    OverlappingDestinations { line: usize, other: usize, range: Range<usize> },
+    Gap { line: usize, range: Range<usize> },
+}
+
+impl Finding {
+    pub fn line(&self) -> usize {
+        match self {
+            Finding::OverlappingSources { line, .. } => *line,
+            Finding::OverlappingDestinations { line, .. } => *line,
+            Finding::Gap { line, .. } => *line,
+        }
+    }
+
+    pub fn is_ambiguous(&self) -> bool {
+        !matches!(self, Finding::Gap { .. })
+    }
+}
+
+impl Display for Finding {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Finding::OverlappingSources { line, other, range } =>
+                write!(f, "line {line}: source range {range:?} overlaps line {other}"),
+            Finding::OverlappingDestinations { line, other, range } =>
+                write!(f, "line {line}: destination range {range:?} overlaps line {other}"),
+            Finding::Gap { line, range } =>
+                write!(f, "line {line}: ids {range:?} before this mapping fall through unmapped"),
+        }
+    }
+}
+
+fn overlaps(section: &Section, range: fn(&Mapping) -> &Range<usize>) -> Vec<(usize, usize, Range<usize>)> {
+    let mut overlaps = Vec::new();
+    let mut widest: Option<(usize, usize)> = None;
+
+    let sorted = section.mappings.iter()
+        .enumerate()
+        .filter(|(_, mapping)| !range(mapping).is_empty())
+        .sorted_by_key(|(_, mapping)| range(mapping).start);
+
+    for (index, mapping) in sorted {
+        let current = range(mapping);
+
+        if let Some((previous, end)) = widest {
+            if current.start < end {
+                let (line, other) = (section.line_of(index), section.line_of(previous));
+                overlaps.push((line.max(other), line.min(other), current.start..current.end.min(end)));
+            }
+
+            if current.end <= end {
+                continue;
+            }
+        }
+
+        widest = Some((index, current.end));
+    }
+
+    overlaps
+}
+
+fn gaps(section: &Section) -> Vec<Finding> {
+    section.mappings.gaps()
+        .map(|range| {
+            let index = section.mappings.iter()
+                .position(|mapping| mapping.source.start == range.end)
+                .expect("Gaps end where a mapping starts");
+
+            Finding::Gap { line: section.line_of(index), range }
+        })
+        .collect()
+}
+
+pub fn validate_section(section: &Section) -> Vec<Finding> {
+    let sources = overlaps(section, |mapping| &mapping.source).into_iter()
+        .map(|(line, other, range)| Finding::OverlappingSources { line, other, range });
+
+    let destinations = overlaps(section, |mapping| &mapping.destination).into_iter()
+        .map(|(line, other, range)| Finding::OverlappingDestinations { line, other, range });
+
+    sources.chain(destinations)
+        .chain(gaps(section))
+        .sorted_by_key(Finding::line)
+        .collect()
+}
+
+pub fn validate(almanac: &Almanac) -> Vec<Finding> {
+    almanac.sections.iter()
+        .flat_map(validate_section)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::day5::mapping::parse;
+    use crate::example;
+
+    use super::{Finding, validate};
+
+    #[test]
+    fn test_validate() {
+        let input = example::input(5, "example");
+        assert!(validate(&parse(&input).unwrap()).is_empty());
+    }
+
+    #[test]
+    fn test_validate_findings() {
+        let almanac = parse("seeds: 79
+
+seed-to-soil map:
+50 98 2
+52 50 40
+60 95 5
+
+soil-to-fertilizer map:
+0 0 10
+5 20 10
+100 10 5").unwrap();
+
+        assert_eq!(validate(&almanac), vec![
+            Finding::OverlappingSources { line: 6, other: 4, range: 98..100 },
+            Finding::OverlappingDestinations { line: 6, other: 5, range: 60..65 },
+            Finding::Gap { line: 6, range: 90..95 },
+            Finding::OverlappingDestinations { line: 10, other: 9, range: 5..10 },
+            Finding::Gap { line: 10, range: 15..20 },
+        ]);
+
+        assert_eq!(validate(&almanac)[0].to_string(), "line 6: source range 98..100 overlaps line 4");
+        assert_eq!(validate(&almanac)[2].is_ambiguous(), false);
+    }
+}
@@ -1,90 +1,234 @@
+use std::ops::RangeInclusive;
+
 use anyhow::{anyhow, Context, Result};
 use itertools::Itertools;
 
-fn get_values(line: &str) -> Result<Vec<u64>> {
+use crate::error::ParseError;
+use crate::solution::Solution;
+
+fn get_values(line: &str) -> Result<Vec<u64>, ParseError> {
     let (_, values) = line.split_once(':')
-        .context("Failed to locate values in {line}")?;
+        .ok_or_else(|| ParseError::at_end(6, line, "`:`"))?;
 
     values.split_ascii_whitespace()
         .map(|value| value.parse::<u64>()
-            .map_err(|err| anyhow!(err)))
+            .map_err(|_| ParseError::at(6, line, value, "number")))
         .collect()
 }
 
-fn parse(input: &Vec<String>) -> Result<Vec<(u64, u64)>> {
-    let (time, distance) = input.iter()
+fn parse(input: &str) -> Result<Vec<(u64, u64)>> {
+    let (time, distance) = input.lines()
         .take(2)
-        .map(|line| get_values(line.as_str()))
-        .collect::<Result<Vec<Vec<u64>>>>()?
+        .enumerate()
+        .map(|(number, line)| get_values(line)
+            .map_err(|err| err.at_line(number + 1)))
+        .collect::<Result<Vec<Vec<u64>>, ParseError>>()?
         .into_iter()
         .collect_tuple()
         .context("Failed to parse races")?;
 
-    Ok(time.into_iter().zip(distance.into_iter()).collect())
+    Ok(time.into_iter().zip(distance).collect())
 }
 
-fn is_winner(rate: u64, time: u64, distance: u64) -> bool {
+fn is_winner(rate: u128, time: u128, distance: u128) -> bool {
     rate * (time - rate) > distance
 }
 
-pub fn part1(input: &Vec<String>) -> Result<u64> {
-    let result = parse(&input)?.into_iter()
-        .map(|(time, distance)|
-            (1..=time)
-                .filter(|rate| is_winner(*rate, time, distance))
-                .count() as u64)
-        .product();
+pub fn winning_interval(time: u128, distance: u128) -> Result<Option<RangeInclusive<u128>>> {
+    let square = time.checked_mul(time)
+        .with_context(|| format!("Race time {time} is too large to solve"))?;
+
+    let Some(discriminant) = distance.checked_mul(4).and_then(|bound| square.checked_sub(bound)) else {
+        return Ok(None);
+    };
+
+    let mut first = (time - discriminant.isqrt()) / 2;
+
+    while first > 0 && is_winner(first - 1, time, distance) {
+        first -= 1;
+    }
+
+    while first <= time / 2 && !is_winner(first, time, distance) {
+        first += 1;
+    }
+
+    if first > time / 2 {
+        return Ok(None);
+    }
+
+    Ok(Some(first..=time - first))
+}
+
+fn count_winners(time: u128, distance: u128) -> Result<u128> {
+    Ok(winning_interval(time, distance)?
+        .map_or(0, |interval| interval.end() - interval.start() + 1))
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RaceReport {
+    pub time: u128,
+    pub record: u128,
+    pub optimal: u128,
+    pub best: u128,
+    pub window: Option<RangeInclusive<u128>>,
+}
+
+impl RaceReport {
+    pub fn new(time: u128, record: u128) -> Result<RaceReport> {
+        let window = winning_interval(time, record)?;
+        let optimal = time / 2;
+
+        Ok(RaceReport { time, record, optimal, best: optimal * (time - optimal), window })
+    }
+
+    pub fn distance(&self, hold: u128) -> u128 {
+        hold * (self.time - hold)
+    }
+
+    pub fn profile(&self) -> impl Iterator<Item=(u128, u128)> + '_ {
+        (0..=self.time).map(|hold| (hold, self.distance(hold)))
+    }
+
+    pub fn margin(&self) -> i128 {
+        self.best as i128 - self.record as i128
+    }
+
+    pub fn winners(&self) -> u128 {
+        self.window.as_ref().map_or(0, |window| window.end() - window.start() + 1)
+    }
+}
+
+pub fn analyze(races: &[(u64, u64)]) -> Result<Vec<RaceReport>> {
+    races.iter()
+        .map(|(time, distance)| RaceReport::new(*time as u128, *distance as u128))
+        .collect()
+}
+
+fn concat(values: impl Iterator<Item=u64>) -> Result<u128> {
+    let digits = values.map(|value| value.to_string()).collect::<String>();
 
-    Ok(result)
+    digits.parse::<u128>()
+        .map_err(|err| anyhow!("Unable to combine races into {digits}: {err}"))
 }
 
-pub fn part2(input: &Vec<String>) -> Result<u64> {
-    let input = input.iter()
-        .map(|line| line.split_whitespace().collect::<String>())
-        .collect_vec();
+pub struct Day6;
 
-    return part1(&input);
+impl Solution for Day6 {
+    type Input<'a> = Vec<(u64, u64)>;
+    type Answer = u128;
+
+    fn parse(input: &str) -> Result<Vec<(u64, u64)>> {
+        parse(input)
+    }
+
+    fn part1(races: &Vec<(u64, u64)>) -> Result<u128> {
+        races.iter()
+            .map(|(time, distance)| count_winners(*time as u128, *distance as u128))
+            .try_fold(1u128, |product, count| product.checked_mul(count?)
+                .context("Product of winning counts does not fit in 128 bits"))
+    }
+
+    fn part2(races: &Vec<(u64, u64)>) -> Result<u128> {
+        let time = concat(races.iter().map(|(time, _)| *time))?;
+        let distance = concat(races.iter().map(|(_, distance)| *distance))?;
+
+        count_winners(time, distance)
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use itertools::Itertools;
-
-    use crate::day6::{parse, part1, part2};
+    use crate::day6::{analyze, count_winners, Day6, is_winner, parse, RaceReport, winning_interval};
+    use crate::error::ParseError;
+    use crate::example;
+    use crate::solution::Solution;
 
     #[test]
     fn test_parse() {
-        let input: Vec<String> = "Time:      7  15   30
-Distance:  9  40  200
-"
-            .lines()
-            .map(String::from)
-            .collect_vec();
-
+        let input = example::input(6, "example");
         assert_eq!(parse(&input).unwrap(), vec![(7, 9), (15, 40), (30, 200)]);
     }
 
     #[test]
-    fn test_part1() {
-        let input: Vec<String> = "Time:      7  15   30
-Distance:  9  40  200
-"
-            .lines()
-            .map(String::from)
-            .collect_vec();
+    fn test_parse_error() {
+        let error = parse("Time:      7  15   30
+Distance:  9  4O  200").unwrap_err();
+
+        assert_eq!(error.downcast::<ParseError>().unwrap(), ParseError {
+            day: 6,
+            line: 2,
+            columns: 14..16,
+            expected: "number".into(),
+            source: "Distance:  9  4O  200".into(),
+        });
+    }
+
+    #[test]
+    fn test_winning_interval() {
+        assert_eq!(winning_interval(7, 9).unwrap(), Some(2..=5));
+        assert_eq!(winning_interval(15, 40).unwrap(), Some(4..=11));
+        assert_eq!(winning_interval(30, 200).unwrap(), Some(11..=19));
+        assert_eq!(winning_interval(71530, 940200).unwrap(), Some(14..=71516));
+
+        assert_eq!(winning_interval(4, 4).unwrap(), None);
+        assert_eq!(winning_interval(4, 3).unwrap(), Some(2..=2));
+        assert_eq!(winning_interval(3, 100).unwrap(), None);
+        assert_eq!(winning_interval(0, 0).unwrap(), None);
+    }
+
+    #[test]
+    fn test_winning_interval_matches_scan() {
+        for time in 0..60 {
+            for distance in 0..=time * time / 4 + 1 {
+                let expected = (0..=time).filter(|rate| is_winner(*rate, time, distance)).count() as u128;
+                assert_eq!(count_winners(time, distance).unwrap(), expected, "time {time}, distance {distance}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_analyze() {
+        let input = example::input(6, "example");
+        let reports = analyze(&parse(&input).unwrap()).unwrap();
+
+        assert_eq!(reports[0], RaceReport { time: 7, record: 9, optimal: 3, best: 12, window: Some(2..=5) });
+        assert_eq!(reports[2].margin(), 25);
+        assert_eq!(reports.iter().map(RaceReport::winners).collect::<Vec<u128>>(), vec![4, 8, 9]);
+
+        assert_eq!(reports[0].profile().collect::<Vec<(u128, u128)>>(), vec![(0, 0), (1, 6), (2, 10), (3, 12), (4, 12), (5, 10), (6, 6), (7, 0)]);
+
+        let losing = RaceReport::new(4, 4).unwrap();
+        assert_eq!((losing.margin(), losing.winners()), (0, 0));
+        assert_eq!(losing.window, None);
+    }
+
+    #[test]
+    fn test_large_races() {
+        let races = vec![(u64::MAX, u64::MAX), (u64::MAX, 0)];
+        assert_eq!(Day6::part1(&races).unwrap(), (u64::MAX as u128 - 3) * (u64::MAX as u128 - 1));
+
+        let races = vec![(4294967296, 1), (1, 0)];
+        assert_eq!(Day6::part2(&races).unwrap(), 42949672960);
+
+        let races = vec![(4294967296, 0), (4294967296, 0)];
+        assert_eq!(Day6::part2(&races).unwrap_err().to_string(), "Race time 42949672964294967296 is too large to solve");
+
+        let races = vec![(1, 9999999999999999999); 2];
+        assert_eq!(Day6::part2(&races).unwrap(), 0);
 
-        assert_eq!(part1(&input).unwrap(), 288);
+        let races = vec![(99999999999999999, 0); 3];
+        assert!(Day6::part2(&races).unwrap_err().to_string().starts_with("Unable to combine races"));
+    }
+
+    #[test]
+    fn test_part1() {
+        let input = example::input(6, "example");
+        assert_eq!(Day6::part1(&parse(&input).unwrap()).unwrap(), 288);
     }
 
     #[test]
     fn test_part2() {
-        let input: Vec<String> = "Time:      7  15   30
-Distance:  9  40  200
-"
-            .lines()
-            .map(String::from)
-            .collect_vec();
-
-        assert_eq!(part2(&input).unwrap(), 71503);
+        let input = example::input(6, "example");
+        assert_eq!(Day6::part2(&parse(&input).unwrap()).unwrap(), 71503);
     }
 }
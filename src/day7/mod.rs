@@ -1,6 +1,9 @@
-use anyhow::{Context, Result};
+use anyhow::Result;
 use itertools::Itertools;
 
+use crate::error::ParseError;
+use crate::solution::Solution;
+
 #[derive(Debug, Copy, Clone, PartialOrd, PartialEq, Ord, Eq)]
 enum Hand {
     HighCard,
@@ -48,22 +51,29 @@ fn rank_hand_with_jokers(cards: [char; 5]) -> Hand {
     }
 }
 
-fn parse(input: &Vec<String>) -> Result<Vec<([char; 5], u32)>> {
-    input.iter()
-        .map(|line| line.split_once(' ').context("Failed to split {line}"))
-        .collect::<Result<Vec<(&str, &str)>>>()?
-        .into_iter()
-        .map(|(hand, bid)| {
-            let bid = bid.parse::<u32>()?;
-
-            let hand: [char; 5] = hand.chars()
-                .take(5)
-                .collect::<Vec<char>>()[..5]
-                .try_into()?;
-
-            Ok((hand, bid))
-        })
-        .collect()
+fn parse_hand(line: &str) -> Result<([char; 5], u32), ParseError> {
+    let (hand, bid) = line.split_once(' ')
+        .ok_or_else(|| ParseError::at_end(7, line, "` `"))?;
+
+    let bid = bid.parse::<u32>()
+        .map_err(|_| ParseError::at(7, line, bid, "bid"))?;
+
+    let hand: [char; 5] = hand.chars()
+        .collect::<Vec<char>>()
+        .try_into()
+        .map_err(|_| ParseError::at(7, line, hand, "hand of 5 cards"))?;
+
+    Ok((hand, bid))
+}
+
+fn parse(input: &str) -> Result<Vec<([char; 5], u32)>> {
+    let hands = input.lines()
+        .enumerate()
+        .map(|(number, line)| parse_hand(line)
+            .map_err(|err| err.at_line(number + 1)))
+        .collect::<Result<Vec<([char; 5], u32)>, ParseError>>()?;
+
+    Ok(hands)
 }
 
 fn card_ranker(cards: [char; 13]) -> impl Fn([char; 5]) -> [u8; 5] {
@@ -71,89 +81,116 @@ fn card_ranker(cards: [char; 13]) -> impl Fn([char; 5]) -> [u8; 5] {
         cards.iter().position(|&item| item == card).unwrap_or(0) as u8)
 }
 
-pub fn part1(input: &Vec<String>) -> Result<u32> {
-    let rank_cards = card_ranker(['2', '3', '4', '5', '6', '7', '8', '9', 'T', 'J', 'Q', 'K', 'A']);
+pub struct Day7;
 
-    let result = parse(input)?.iter()
-        .sorted_by_key(|(hand, _)| (rank_hand(*hand), rank_cards(*hand)))
-        .collect_vec()
-        .into_iter()
-        .map(|(_, bid)| bid)
-        .zip(1u32..)
-        .fold(0u32, |value, (bid, rank)| value + rank * bid);
+impl Solution for Day7 {
+    type Input<'a> = Vec<([char; 5], u32)>;
+    type Answer = u32;
 
-    Ok(result)
-}
+    fn parse(input: &str) -> Result<Vec<([char; 5], u32)>> {
+        parse(input)
+    }
 
-pub fn part2(input: &Vec<String>) -> Result<u32> {
-    let rank_cards = card_ranker(['J', '2', '3', '4', '5', '6', '7', '8', '9', 'T', 'Q', 'K', 'A']);
+    fn part1(hands: &Vec<([char; 5], u32)>) -> Result<u32> {
+        let rank_cards = card_ranker(['2', '3', '4', '5', '6', '7', '8', '9', 'T', 'J', 'Q', 'K', 'A']);
 
-    let result = parse(input)?.iter()
-        .sorted_by_key(|(hand, _)| (rank_hand_with_jokers(*hand), rank_cards(*hand)))
-        .collect_vec()
-        .into_iter()
-        .map(|(_, bid)| bid)
-        .zip(1u32..)
-        .fold(0u32, |value, (bid, rank)| value + rank * bid);
+        let result = hands.iter()
+            .sorted_by_key(|(hand, _)| (rank_hand(*hand), rank_cards(*hand)))
+            .collect_vec()
+            .into_iter()
+            .map(|(_, bid)| bid)
+            .zip(1u32..)
+            .fold(0u32, |value, (bid, rank)| value + rank * bid);
 
-    Ok(result)
+        Ok(result)
+    }
+
+    fn part2(hands: &Vec<([char; 5], u32)>) -> Result<u32> {
+        let rank_cards = card_ranker(['J', '2', '3', '4', '5', '6', '7', '8', '9', 'T', 'Q', 'K', 'A']);
+
+        let result = hands.iter()
+            .sorted_by_key(|(hand, _)| (rank_hand_with_jokers(*hand), rank_cards(*hand)))
+            .collect_vec()
+            .into_iter()
+            .map(|(_, bid)| bid)
+            .zip(1u32..)
+            .fold(0u32, |value, (bid, rank)| value + rank * bid);
+
+        Ok(result)
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{Hand, parse, part1, part2, rank_hand, rank_hand_with_jokers};
+    use crate::error::ParseError;
+    use crate::example;
+    use crate::solution::Solution;
 
-    const INPUT: &str = "32T3K 765
-T55J5 684
-KK677 28
-KTJJT 220
-QQQJA 483
-";
+    use super::{Day7, Hand, parse, rank_hand, rank_hand_with_jokers};
 
     #[test]
     fn test_parse() {
-        let input = INPUT.lines().map(String::from).collect();
+        let input = example::input(7, "example");
         let hands = parse(&input).unwrap();
 
         assert_eq!(hands.len(), 5);
         assert_eq!(*hands.first().unwrap(), (['3', '2', 'T', '3', 'K'], 765));
-        assert_eq!(*hands.iter().nth(1).unwrap(), (['T', '5', '5', 'J', '5'], 684));
+        assert_eq!(hands[1], (['T', '5', '5', 'J', '5'], 684));
         assert_eq!(*hands.last().unwrap(), (['Q', 'Q', 'Q', 'J', 'A'], 483));
     }
 
+    #[test]
+    fn test_parse_error() {
+        let error = parse("32T3K 765\nT55J 684").unwrap_err();
+
+        assert_eq!(error.downcast::<ParseError>().unwrap(), ParseError {
+            day: 7,
+            line: 2,
+            columns: 0..4,
+            expected: "hand of 5 cards".into(),
+            source: "T55J 684".into(),
+        });
+
+        let error = parse("32T3K").unwrap_err().downcast::<ParseError>().unwrap();
+        assert_eq!((error.columns, error.expected.as_str()), (5..5, "` `"));
+
+        let error = parse("32T3K bid").unwrap_err().downcast::<ParseError>().unwrap();
+        assert_eq!((error.columns, error.expected.as_str()), (6..9, "bid"));
+    }
+
     #[test]
     fn test_rank_hand() {
-        let input = INPUT.lines().map(String::from).collect();
+        let input = example::input(7, "example");
         let hands = parse(&input).unwrap();
 
-        assert_eq!(rank_hand(hands.iter().nth(0).unwrap().0), Hand::OnePair);
-        assert_eq!(rank_hand(hands.iter().nth(1).unwrap().0), Hand::ThreeOfAKind);
-        assert_eq!(rank_hand(hands.iter().nth(2).unwrap().0), Hand::TwoPair);
-        assert_eq!(rank_hand(hands.iter().nth(3).unwrap().0), Hand::TwoPair);
-        assert_eq!(rank_hand(hands.iter().nth(4).unwrap().0), Hand::ThreeOfAKind);
+        assert_eq!(rank_hand(hands[0].0), Hand::OnePair);
+        assert_eq!(rank_hand(hands[1].0), Hand::ThreeOfAKind);
+        assert_eq!(rank_hand(hands[2].0), Hand::TwoPair);
+        assert_eq!(rank_hand(hands[3].0), Hand::TwoPair);
+        assert_eq!(rank_hand(hands[4].0), Hand::ThreeOfAKind);
     }
 
     #[test]
     fn test_rank_hand_with_jokers() {
-        let input = INPUT.lines().map(String::from).collect();
+        let input = example::input(7, "example");
         let hands = parse(&input).unwrap();
 
-        assert_eq!(rank_hand_with_jokers(hands.iter().nth(0).unwrap().0), Hand::OnePair);
-        assert_eq!(rank_hand_with_jokers(hands.iter().nth(1).unwrap().0), Hand::FourOfAKind);
-        assert_eq!(rank_hand_with_jokers(hands.iter().nth(2).unwrap().0), Hand::TwoPair);
-        assert_eq!(rank_hand_with_jokers(hands.iter().nth(3).unwrap().0), Hand::FourOfAKind);
-        assert_eq!(rank_hand_with_jokers(hands.iter().nth(4).unwrap().0), Hand::FourOfAKind);
+        assert_eq!(rank_hand_with_jokers(hands[0].0), Hand::OnePair);
+        assert_eq!(rank_hand_with_jokers(hands[1].0), Hand::FourOfAKind);
+        assert_eq!(rank_hand_with_jokers(hands[2].0), Hand::TwoPair);
+        assert_eq!(rank_hand_with_jokers(hands[3].0), Hand::FourOfAKind);
+        assert_eq!(rank_hand_with_jokers(hands[4].0), Hand::FourOfAKind);
     }
 
     #[test]
     fn test_part_1() {
-        let input = INPUT.lines().map(String::from).collect();
-        assert_eq!(part1(&input).unwrap(), 6440);
+        let input = example::input(7, "example");
+        assert_eq!(Day7::part1(&parse(&input).unwrap()).unwrap(), 6440);
     }
 
     #[test]
     fn test_part_2() {
-        let input = INPUT.lines().map(String::from).collect();
-        assert_eq!(part2(&input).unwrap(), 5905);
+        let input = example::input(7, "example");
+        assert_eq!(Day7::part2(&parse(&input).unwrap()).unwrap(), 5905);
     }
 }
\ No newline at end of file
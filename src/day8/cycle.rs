@@ -0,0 +1,194 @@
+use anyhow::{Context, Result};
+use itertools::{Either, Itertools};
+
+use crate::day8::network::Network;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Cycle {
+    pub tail: usize,
+    pub length: usize,
+    pub tail_hits: Vec<usize>,
+    pub offsets: Vec<usize>,
+}
+
+impl Cycle {
+    pub fn matches(&self, step: usize) -> bool {
+        if step < self.tail {
+            return self.tail_hits.contains(&step);
+        }
+
+        self.offsets.contains(&((step - self.tail) % self.length))
+    }
+
+    pub fn first(&self) -> Option<usize> {
+        self.tail_hits.first().copied()
+            .or_else(|| self.offsets.first().map(|offset| self.tail + offset))
+    }
+
+    pub fn is_reachable(&self) -> bool {
+        !self.tail_hits.is_empty() || !self.offsets.is_empty()
+    }
+}
+
+pub fn find_cycle(network: &Network, start: &str, target: impl Fn(&str) -> bool) -> Result<Cycle> {
+    let tape = network.tape()?;
+    let goals = (0..network.len()).map(|node| target(network.name(node))).collect::<Vec<bool>>();
+    let mut seen = vec![usize::MAX; network.len() * tape.len()];
+    let mut hits = Vec::new();
+    let mut node = network.start(start)?;
+    let mut step = 0;
+
+    loop {
+        let index = step % tape.len();
+        let state = node * tape.len() + index;
+
+        if seen[state] != usize::MAX {
+            let tail = seen[state];
+            let (tail_hits, offsets) = hits.into_iter()
+                .partition_map(|hit| if hit < tail { Either::Left(hit) } else { Either::Right(hit - tail) });
+
+            return Ok(Cycle { tail, length: step - tail, tail_hits, offsets });
+        }
+
+        seen[state] = step;
+
+        if goals[node] {
+            hits.push(step);
+        }
+
+        node = network.next(node, tape[index])?;
+        step += 1;
+    }
+}
+
+fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    if b == 0 {
+        return (a, 1, 0);
+    }
+
+    let (gcd, x, y) = extended_gcd(b, a % b);
+    (gcd, y, x - (a / b) * y)
+}
+
+fn crt((r1, m1): (i128, i128), (r2, m2): (i128, i128)) -> Option<(i128, i128)> {
+    let (gcd, inverse, _) = extended_gcd(m1, m2);
+
+    if (r2 - r1) % gcd != 0 {
+        return None;
+    }
+
+    let lcm = m1 / gcd * m2;
+    let k = ((r2 - r1) / gcd * inverse).rem_euclid(m2 / gcd);
+
+    Some(((r1 + m1 * k).rem_euclid(lcm), lcm))
+}
+
+pub fn first_common_step(cycles: &[Cycle]) -> Result<Option<u64>> {
+    let Some(floor) = cycles.iter().map(|cycle| cycle.tail).max() else {
+        return Ok(None);
+    };
+
+    if let Some(step) = (0..floor).find(|step| cycles.iter().all(|cycle| cycle.matches(*step))) {
+        return Ok(Some(step as u64));
+    }
+
+    let (residues, modulus) = cycles.iter().fold((vec![0], 1), |(residues, modulus), cycle| {
+        let length = cycle.length as i128;
+
+        let merged = residues.iter()
+            .cartesian_product(&cycle.offsets)
+            .filter_map(|(residue, offset)| crt((*residue, modulus), ((cycle.tail + offset) as i128, length)))
+            .map(|(residue, _)| residue)
+            .sorted()
+            .dedup()
+            .collect::<Vec<i128>>();
+
+        (merged, modulus / extended_gcd(modulus, length).0 * length)
+    });
+
+    residues.into_iter()
+        .map(|residue| residue + ((floor as i128 - residue).max(0) + modulus - 1) / modulus * modulus)
+        .min()
+        .map(|step| u64::try_from(step).with_context(|| format!("The first common step {step} does not fit in u64")))
+        .transpose()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::day8::parse;
+    use crate::example;
+
+    use super::{crt, Cycle, find_cycle, first_common_step};
+
+    #[test]
+    fn test_find_cycle() {
+        let ghosts = example::input(8, "example3");
+        let network = parse(&ghosts).unwrap();
+
+        let cycle = find_cycle(&network, "11A", |id| id.ends_with('Z')).unwrap();
+        assert_eq!(cycle, Cycle { tail: 1, length: 2, tail_hits: vec![], offsets: vec![1] });
+
+        let cycle = find_cycle(&network, "22A", |id| id.ends_with('Z')).unwrap();
+        assert_eq!(cycle, Cycle { tail: 1, length: 6, tail_hits: vec![], offsets: vec![2, 5] });
+
+        let cycle = find_cycle(&network, "XXX", |id| id.ends_with('Z')).unwrap();
+        assert_eq!(cycle, Cycle { tail: 0, length: 2, tail_hits: vec![], offsets: vec![] });
+        assert!(!cycle.is_reachable());
+
+        assert!(find_cycle(&network, "33A", |id| id.ends_with('Z')).is_err());
+    }
+
+    #[test]
+    fn test_matches() {
+        let cycle = Cycle { tail: 3, length: 4, tail_hits: vec![1], offsets: vec![0, 2] };
+
+        assert_eq!((0..12).filter(|step| cycle.matches(*step)).collect::<Vec<usize>>(), vec![1, 3, 5, 7, 9, 11]);
+        assert_eq!(cycle.first(), Some(1));
+        assert_eq!(Cycle { tail: 3, length: 4, tail_hits: vec![], offsets: vec![2] }.first(), Some(5));
+        assert_eq!(Cycle { tail: 3, length: 4, tail_hits: vec![], offsets: vec![] }.first(), None);
+    }
+
+    #[test]
+    fn test_crt() {
+        assert_eq!(crt((0, 1), (2, 3)), Some((2, 3)));
+        assert_eq!(crt((2, 3), (3, 5)), Some((8, 15)));
+        assert_eq!(crt((2, 4), (4, 6)), Some((10, 12)));
+        assert_eq!(crt((1, 4), (2, 6)), None);
+    }
+
+    #[test]
+    fn test_first_common_step() {
+        let input = example::input(8, "example4");
+        let network = parse(&input).unwrap();
+
+        let cycles = ["AAA", "ZZA"].map(|start| find_cycle(&network, start, |id| id.ends_with('Z')).unwrap());
+        assert_eq!(first_common_step(&cycles).unwrap(), Some(2));
+
+        let cycles = ["AAA", "DDD"].map(|start| find_cycle(&network, start, |id| id.ends_with('Z')).unwrap());
+        assert_eq!(first_common_step(&cycles).unwrap(), None);
+
+        let ghosts = example::input(8, "example3");
+        let network = parse(&ghosts).unwrap();
+        let cycles = ["11A", "22A"].map(|start| find_cycle(&network, start, |id| id.ends_with('Z')).unwrap());
+        assert_eq!(first_common_step(&cycles).unwrap(), Some(6));
+
+        assert_eq!(first_common_step(&[]).unwrap(), None);
+    }
+
+    #[test]
+    fn test_first_common_step_many_hits() {
+        let dense = Cycle { tail: 0, length: 600, tail_hits: vec![], offsets: (1..600).step_by(3).collect() };
+        let sparse = Cycle { tail: 0, length: 600, tail_hits: vec![], offsets: vec![301] };
+
+        let cycles = [vec![dense.clone(); 5], vec![sparse]].concat();
+        assert_eq!(first_common_step(&cycles).unwrap(), Some(301));
+
+        let shifted = Cycle { tail: 0, length: 900, tail_hits: vec![], offsets: vec![2] };
+        assert_eq!(first_common_step(&[dense, shifted]).unwrap(), None);
+
+        let cycles = [(4294967291, 0), (4294967279, 1), (4294967231, 2)]
+            .map(|(length, offset)| Cycle { tail: 0, length, tail_hits: vec![], offsets: vec![offset] });
+        let error = first_common_step(&cycles).unwrap_err();
+        assert_eq!(error.to_string(), "The first common step 9160756105129883262185198294 does not fit in u64");
+    }
+}
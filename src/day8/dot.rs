@@ -0,0 +1,91 @@
+use std::collections::HashSet;
+use std::fmt::Write;
+
+use itertools::Itertools;
+
+use crate::day8::network::Network;
+use crate::day8::traverse::Route;
+
+fn node_style(id: &str, on_path: bool) -> Vec<&'static str> {
+    let mut style = Vec::new();
+
+    if id.ends_with('A') {
+        style.extend(["shape=box", "style=filled", "fillcolor=palegreen"]);
+    } else if id.ends_with('Z') {
+        style.extend(["shape=doublecircle", "style=filled", "fillcolor=lightcoral"]);
+    }
+
+    if on_path {
+        style.extend(["color=red", "penwidth=2"]);
+    }
+
+    style
+}
+
+pub fn to_dot(network: &Network, route: &Route) -> String {
+    let nodes = route.path.iter().copied().collect::<HashSet<&str>>();
+    let edges = route.path.iter().copied().zip(route.directions.chars()).collect::<HashSet<(&str, char)>>();
+
+    let mut output = String::from("digraph day8 {\n");
+
+    for node in network.nodes().sorted_by_key(|node| network.name(*node)) {
+        let id = network.name(node);
+        let style = node_style(id, nodes.contains(id));
+
+        if style.is_empty() {
+            writeln!(output, "  \"{id}\";").unwrap();
+        } else {
+            writeln!(output, "  \"{id}\" [{}];", style.join(", ")).unwrap();
+        }
+
+        for (label, target) in ['L', 'R'].into_iter().zip(network.edges(node).map(|target| network.name(target))) {
+            let highlight = if edges.contains(&(id, label)) { ", color=red, penwidth=2" } else { "" };
+            writeln!(output, "  \"{id}\" -> \"{target}\" [label=\"{label}\"{highlight}];").unwrap();
+        }
+    }
+
+    output.push_str("}\n");
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::day8::parse;
+    use crate::day8::traverse::Route;
+    use crate::example;
+
+    use super::to_dot;
+
+    #[test]
+    fn test_to_dot() {
+        let input = example::input(8, "example2");
+        let network = parse(&input).unwrap();
+
+        assert_eq!(to_dot(&network, &Route::default()), r#"digraph day8 {
+  "AAA" [shape=box, style=filled, fillcolor=palegreen];
+  "AAA" -> "BBB" [label="L"];
+  "AAA" -> "BBB" [label="R"];
+  "BBB";
+  "BBB" -> "AAA" [label="L"];
+  "BBB" -> "ZZZ" [label="R"];
+  "ZZZ" [shape=doublecircle, style=filled, fillcolor=lightcoral];
+  "ZZZ" -> "ZZZ" [label="L"];
+  "ZZZ" -> "ZZZ" [label="R"];
+}
+"#);
+    }
+
+    #[test]
+    fn test_to_dot_path() {
+        let input = example::input(8, "example2");
+        let network = parse(&input).unwrap();
+        let dot = to_dot(&network, &Route { path: vec!["AAA", "BBB", "ZZZ"], directions: "LR".into() });
+
+        assert!(dot.contains(r#"  "BBB" [color=red, penwidth=2];"#));
+        assert!(dot.contains(r#"  "BBB" -> "ZZZ" [label="R", color=red, penwidth=2];"#));
+        assert!(dot.contains(r#"  "BBB" -> "AAA" [label="L"];"#));
+        assert!(dot.contains(r#"  "AAA" -> "BBB" [label="L", color=red, penwidth=2];"#));
+        assert!(dot.contains(r#"  "AAA" -> "BBB" [label="R"];"#));
+        assert!(dot.contains(r#"  "ZZZ" [shape=doublecircle, style=filled, fillcolor=lightcoral, color=red, penwidth=2];"#));
+    }
+}
@@ -1,83 +1,132 @@
-use std::collections::HashMap;
+use anyhow::Result;
 
-use anyhow::{Context, Result};
+use crate::day8::network::Network;
+use crate::day8::traverse::{Selector, traverse};
+use crate::error::ParseError;
+use crate::solution::Solution;
 
-#[derive(Debug, PartialEq, Eq)]
-struct Location {
-    id: String,
-    left: String,
-    right: String,
+pub mod cycle;
+pub mod dot;
+pub mod network;
+pub mod traverse;
+pub mod validate;
+
+fn parse_location(line: &str) -> Result<(&str, &str, &str), ParseError> {
+    let (id, locations) = line.split_once(" = ")
+        .ok_or_else(|| ParseError::at_end(8, line, "` = `"))?;
+
+    let (left, right) = locations.strip_prefix('(')
+        .and_then(|location| location.strip_suffix(')'))
+        .and_then(|location| location.split_once(", "))
+        .ok_or_else(|| ParseError::at(8, line, locations, "`(<left>, <right>)`"))?;
+
+    Ok((id, left, right))
 }
 
-fn parse(input: &Vec<String>) -> Result<(String, HashMap<String, Location>)> {
-    let (directions, locations) = input.split_at(1);
-
-    let directions = directions.iter().next()
-        .context("Failed to parse directions from {input}")?;
-
-    let locations: HashMap<String, Location> = locations.iter()
-        .skip(1)
-        .map(|line| line.split_once(" = ")
-            .context("Failed to split line")
-            .and_then(|(id, locations)| locations.strip_prefix('(')
-                .and_then(|location| location.strip_suffix(')'))
-                .and_then(|location| location.split_once(", "))
-                .context("Failed to parse {line}")
-                .map(|(left, right)| (id.into(), Location {
-                    id: id.into(),
-                    left: left.into(),
-                    right: right.into(),
-                }))))
-        .collect::<Result<HashMap<String, Location>>>()?;
-
-    Ok((directions.into(), locations))
+fn parse(input: &str) -> Result<Network<'_>> {
+    let mut lines = input.lines().enumerate();
+
+    let directions = match lines.next() {
+        Some((_, directions)) if !directions.is_empty() => directions,
+        _ => Err(ParseError::at_end(8, "", "directions"))?,
+    };
+
+    let mut network = Network::new(directions);
+
+    for (number, line) in lines.skip(1) {
+        let (id, left, right) = parse_location(line)
+            .map_err(|err| err.at_line(number + 1))?;
+
+        network.define(id, left, right, number + 1);
+    }
+
+    Ok(network)
 }
 
-pub fn part1(input: &Vec<String>) -> Result<u32> {
-    let (directions, locations) = parse(&input)?;
-
-    directions.chars()
-        .cycle()
-        .scan(locations.get("AAA"), |location, direction| {
-            *location = match direction {
-                'L' => location.and_then(|location| locations.get(&location.left)),
-                'R' => location.and_then(|location| locations.get(&location.right)),
-                _ => None
-            };
-
-            return *location;
-        })
-        .zip(1u32..)
-        .find(|(location, _)| location.id == "ZZZ")
-        .map(|(_, steps)| steps)
-        .context("Failed to find ZZZ")
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input<'a> = Network<'a>;
+    type Answer = u64;
+
+    fn parse(input: &str) -> Result<Network<'_>> {
+        parse(input)
+    }
+
+    fn part1(network: &Network) -> Result<u64> {
+        Ok(traverse(network, &Selector::Exact("AAA"), &Selector::Exact("ZZZ"))?.steps)
+    }
+
+    fn part2(network: &Network) -> Result<u64> {
+        Ok(traverse(network, &Selector::Suffix("A"), &Selector::Suffix("Z"))?.steps)
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{Location, parse, part1};
+    use crate::error::ParseError;
+    use crate::example;
+    use crate::solution::Solution;
 
-    const INPUT: &str = "LLR
-
-AAA = (BBB, BBB)
-BBB = (AAA, ZZZ)
-ZZZ = (ZZZ, ZZZ)";
+    use super::{Day8, parse};
 
     #[test]
     fn test_parse() {
-        let input: Vec<String> = INPUT.lines().map(String::from).collect();
-        let (directions, locations) = parse(&input).unwrap();
-
-        assert_eq!(directions, "LLR".to_string());
-        assert_eq!(locations.len(), 3);
-        assert_eq!(locations.get("AAA").unwrap(), &Location { id: "AAA".into(), left: "BBB".into(), right: "BBB".into() });
-        assert_eq!(locations.get("BBB").unwrap(), &Location { id: "BBB".into(), left: "AAA".into(), right: "ZZZ".into() });
-        assert_eq!(locations.get("ZZZ").unwrap(), &Location { id: "ZZZ".into(), left: "ZZZ".into(), right: "ZZZ".into() });
+        let input = example::input(8, "example2");
+        let network = parse(&input).unwrap();
+        let [aaa, bbb, zzz] = ["AAA", "BBB", "ZZZ"].map(|id| network.node(id).unwrap());
+
+        assert_eq!(network.directions, "LLR");
+        assert_eq!(network.len(), 3);
+        assert_eq!(network.edges(aaa), [bbb, bbb]);
+        assert_eq!(network.edges(bbb), [aaa, zzz]);
+        assert_eq!(network.edges(zzz), [zzz, zzz]);
+        assert_eq!(network.line(bbb), Some(4));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = parse("LLR
+
+AAA = (BBB, BBB)
+BBB = AAA, ZZZ").unwrap_err();
+
+        assert_eq!(error.downcast::<ParseError>().unwrap(), ParseError {
+            day: 8,
+            line: 4,
+            columns: 6..14,
+            expected: "`(<left>, <right>)`".into(),
+            source: "BBB = AAA, ZZZ".into(),
+        });
+
+        let error = parse("").unwrap_err().downcast::<ParseError>().unwrap();
+        assert_eq!(error.expected, "directions");
     }
 
     #[test]
     fn test_part1() {
-        let input: Vec<String> = INPUT.lines().map(String::from).collect();
-        assert_eq!(part1(&input).unwrap(), 6)
+        let input = example::input(8, "example2");
+        assert_eq!(Day8::part1(&parse(&input).unwrap()).unwrap(), 6)
+    }
+
+    #[test]
+    fn test_part1_redefined() {
+        let network = parse("L\n\nAAA = (BBB, BBB)\nAAA = (ZZZ, ZZZ)\nZZZ = (ZZZ, ZZZ)").unwrap();
+        assert_eq!(Day8::part1(&network).unwrap(), 1);
+    }
+
+    #[test]
+    fn test_part1_unreachable() {
+        let error = Day8::part1(&parse("L\n\nAAA = (BBB, BBB)\nBBB = (AAA, ZZZ)\nZZZ = (ZZZ, ZZZ)").unwrap()).unwrap_err();
+        assert_eq!(error.to_string(), "ZZZ is unreachable from AAA");
+
+        assert!(Day8::part1(&parse("L\n\nBBB = (BBB, BBB)").unwrap()).is_err());
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = example::input(8, "example2");
+        assert_eq!(Day8::part2(&parse(&input).unwrap()).unwrap(), 6);
+        assert!(Day8::part2(&parse("L\n\nAAA = (BBB, BBB)").unwrap()).is_err());
     }
 }
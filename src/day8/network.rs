@@ -0,0 +1,133 @@
+use std::collections::HashMap;
+
+use anyhow::{bail, Context, Result};
+
+#[derive(Debug, Default, PartialEq)]
+pub struct Network<'a> {
+    pub directions: &'a str,
+    names: Vec<&'a str>,
+    index: HashMap<&'a str, usize>,
+    edges: Vec<[usize; 2]>,
+    lines: Vec<Option<usize>>,
+    duplicates: Vec<(usize, usize, usize)>,
+}
+
+impl<'a> Network<'a> {
+    pub fn new(directions: &'a str) -> Network<'a> {
+        Network { directions, ..Network::default() }
+    }
+
+    pub fn intern(&mut self, name: &'a str) -> usize {
+        if let Some(node) = self.index.get(name) {
+            return *node;
+        }
+
+        let node = self.names.len();
+        self.names.push(name);
+        self.index.insert(name, node);
+        self.edges.push([node, node]);
+        self.lines.push(None);
+        node
+    }
+
+    pub fn define(&mut self, name: &'a str, left: &'a str, right: &'a str, line: usize) {
+        let node = self.intern(name);
+        let edges = [self.intern(left), self.intern(right)];
+
+        if let Some(first) = self.lines[node] {
+            self.duplicates.push((node, first, line));
+        }
+
+        self.edges[node] = edges;
+        self.lines[node] = Some(line);
+    }
+
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    pub fn node(&self, name: &str) -> Option<usize> {
+        self.index.get(name).copied()
+    }
+
+    pub fn name(&self, node: usize) -> &'a str {
+        self.names[node]
+    }
+
+    pub fn line(&self, node: usize) -> Option<usize> {
+        self.lines[node]
+    }
+
+    pub fn is_defined(&self, node: usize) -> bool {
+        self.lines[node].is_some()
+    }
+
+    pub fn duplicates(&self) -> &[(usize, usize, usize)] {
+        &self.duplicates
+    }
+
+    pub fn nodes(&self) -> impl Iterator<Item=usize> + '_ {
+        (0..self.len()).filter(|node| self.is_defined(*node))
+    }
+
+    pub fn edges(&self, node: usize) -> [usize; 2] {
+        self.edges[node]
+    }
+
+    pub fn start(&self, name: &str) -> Result<usize> {
+        self.node(name)
+            .filter(|node| self.is_defined(*node))
+            .with_context(|| format!("Unknown location {name}"))
+    }
+
+    pub fn tape(&self) -> Result<Vec<usize>> {
+        self.directions.chars()
+            .map(|direction| match direction {
+                'L' => Ok(0),
+                'R' => Ok(1),
+                _ => bail!("Invalid direction {direction}"),
+            })
+            .collect()
+    }
+
+    pub fn next(&self, node: usize, direction: usize) -> Result<usize> {
+        if !self.is_defined(node) {
+            bail!("Unknown location {}", self.name(node));
+        }
+
+        Ok(self.edges[node][direction])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Network;
+
+    #[test]
+    fn test_network() {
+        let mut network = Network::new("LR");
+        network.define("AAA", "BBB", "CCC", 3);
+        network.define("BBB", "AAA", "AAA", 4);
+        network.define("AAA", "AAA", "AAA", 5);
+
+        let [aaa, bbb, ccc] = ["AAA", "BBB", "CCC"].map(|name| network.node(name).unwrap());
+
+        assert_eq!(network.len(), 3);
+        assert_eq!(network.edges(aaa), [aaa, aaa]);
+        assert_eq!(network.edges(bbb), [aaa, aaa]);
+        assert_eq!(network.line(aaa), Some(5));
+        assert_eq!(network.line(ccc), None);
+        assert_eq!(network.duplicates(), &[(aaa, 3, 5)]);
+        assert_eq!(network.nodes().map(|node| network.name(node)).collect::<Vec<&str>>(), vec!["AAA", "BBB"]);
+
+        assert_eq!(network.next(bbb, 1).unwrap(), aaa);
+        assert_eq!(network.next(ccc, 0).unwrap_err().to_string(), "Unknown location CCC");
+        assert!(network.start("CCC").is_err());
+        assert_eq!(network.tape().unwrap(), vec![0, 1]);
+        assert_eq!(Network::new("LX").tape().unwrap_err().to_string(), "Invalid direction X");
+    }
+}
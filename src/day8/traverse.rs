@@ -0,0 +1,236 @@
+use std::collections::VecDeque;
+use std::fmt::{Display, Formatter};
+
+use anyhow::{bail, Context, Result};
+
+use crate::day8::cycle::{Cycle, find_cycle, first_common_step};
+use crate::day8::network::Network;
+
+pub enum Selector<'s> {
+    Exact(&'s str),
+    Suffix(&'s str),
+    Custom(Box<dyn Fn(&str) -> bool + 's>),
+}
+
+impl Selector<'_> {
+    pub fn matches(&self, id: &str) -> bool {
+        match self {
+            Selector::Exact(name) => id == *name,
+            Selector::Suffix(suffix) => id.ends_with(suffix),
+            Selector::Custom(predicate) => predicate(id),
+        }
+    }
+}
+
+impl Display for Selector<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Selector::Exact(name) => write!(f, "{name}"),
+            Selector::Suffix(suffix) => write!(f, "**{suffix}"),
+            Selector::Custom(_) => write!(f, "custom nodes"),
+        }
+    }
+}
+
+pub struct Walk<'n, 'a> {
+    network: &'n Network<'a>,
+    tape: Vec<usize>,
+    node: Option<usize>,
+    step: usize,
+}
+
+impl<'n, 'a> Walk<'n, 'a> {
+    pub fn new(network: &'n Network<'a>, start: &str) -> Result<Walk<'n, 'a>> {
+        Ok(Walk { network, tape: network.tape()?, node: Some(network.start(start)?), step: 0 })
+    }
+}
+
+impl<'a> Iterator for Walk<'_, 'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        let node = self.node?;
+
+        self.node = self.network.next(node, self.tape[self.step % self.tape.len()]).ok();
+        self.step += 1;
+
+        Some(self.network.name(node))
+    }
+}
+
+pub struct Traversal<'n, 'a> {
+    network: &'n Network<'a>,
+    pub starts: Vec<&'a str>,
+    pub cycles: Vec<Cycle>,
+    pub steps: u64,
+}
+
+impl<'n, 'a> Traversal<'n, 'a> {
+    pub fn path(&self, start: usize) -> impl Iterator<Item=&'a str> + 'n {
+        Walk::new(self.network, self.starts[start])
+            .expect("Traversed starts are defined")
+            .take(self.steps as usize + 1)
+    }
+
+    pub fn route(&self, start: usize) -> Route<'a> {
+        Route {
+            path: self.path(start).collect(),
+            directions: self.network.directions.chars().cycle().take(self.steps as usize).collect(),
+        }
+    }
+}
+
+pub fn traverse<'n, 'a>(network: &'n Network<'a>, starts: &Selector, goal: &Selector) -> Result<Traversal<'n, 'a>> {
+    let names = network.nodes()
+        .map(|node| network.name(node))
+        .filter(|id| starts.matches(id))
+        .collect::<Vec<&str>>();
+
+    if names.is_empty() {
+        bail!("No nodes match {starts}");
+    }
+
+    let cycles = names.iter()
+        .map(|start| find_cycle(network, start, |id| goal.matches(id)))
+        .collect::<Result<Vec<Cycle>>>()?;
+
+    let steps = first_common_step(&cycles)?
+        .with_context(|| format!("{goal} is unreachable from {starts}"))?;
+
+    Ok(Traversal { network, starts: names, cycles, steps })
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Route<'a> {
+    pub path: Vec<&'a str>,
+    pub directions: String,
+}
+
+impl Route<'_> {
+    pub fn steps(&self) -> usize {
+        self.directions.len()
+    }
+}
+
+fn route<'a>(network: &Network<'a>, previous: &[Option<(usize, char)>], goal: usize) -> Route<'a> {
+    let mut path = vec![network.name(goal)];
+    let mut directions = Vec::new();
+    let mut current = goal;
+
+    while let Some((node, direction)) = previous[current] {
+        path.push(network.name(node));
+        directions.push(direction);
+        current = node;
+    }
+
+    path.reverse();
+    Route { path, directions: directions.into_iter().rev().collect() }
+}
+
+pub fn shortest_path<'a>(network: &Network<'a>, start: &str, goal: &Selector) -> Result<Route<'a>> {
+    let start = network.start(start)?;
+    let mut previous: Vec<Option<(usize, char)>> = vec![None; network.len()];
+    let mut reached = vec![false; network.len()];
+    let mut queue = VecDeque::from([start]);
+    reached[start] = true;
+
+    while let Some(node) = queue.pop_front() {
+        if goal.matches(network.name(node)) {
+            return Ok(route(network, &previous, node));
+        }
+
+        if !network.is_defined(node) {
+            continue;
+        }
+
+        for (direction, target) in ['L', 'R'].into_iter().zip(network.edges(node)) {
+            if !reached[target] {
+                reached[target] = true;
+                previous[target] = Some((node, direction));
+                queue.push_back(target);
+            }
+        }
+    }
+
+    bail!("{goal} is unreachable from {}", network.name(start))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::day8::parse;
+    use crate::example;
+
+    use super::{Route, Selector, shortest_path, traverse, Walk};
+
+    #[test]
+    fn test_selector() {
+        assert!(Selector::Exact("AAA").matches("AAA"));
+        assert!(!Selector::Exact("AAA").matches("BAAA"));
+        assert!(Selector::Suffix("Z").matches("11Z"));
+        assert!(Selector::Custom(Box::new(|id| id.starts_with("22"))).matches("22B"));
+        assert_eq!(Selector::Suffix("A").to_string(), "**A");
+    }
+
+    #[test]
+    fn test_walk() {
+        let ghosts = example::input(8, "example3");
+        let network = parse(&ghosts).unwrap();
+
+        assert_eq!(Walk::new(&network, "22A").unwrap().take(5).collect::<Vec<&str>>(), vec!["22A", "22B", "22C", "22Z", "22B"]);
+        assert!(Walk::new(&network, "33A").is_err());
+
+        let network = parse("L\n\nAAA = (BBB, BBB)").unwrap();
+        assert_eq!(Walk::new(&network, "AAA").unwrap().collect::<Vec<&str>>(), vec!["AAA", "BBB"]);
+    }
+
+    #[test]
+    fn test_traverse() {
+        let ghosts = example::input(8, "example3");
+        let network = parse(&ghosts).unwrap();
+
+        let traversal = traverse(&network, &Selector::Suffix("A"), &Selector::Suffix("Z")).unwrap();
+        assert_eq!((traversal.starts.clone(), traversal.steps), (vec!["11A", "22A"], 6));
+        assert_eq!(traversal.path(0).collect::<Vec<&str>>(), vec!["11A", "11B", "11Z", "11B", "11Z", "11B", "11Z"]);
+        assert_eq!(traversal.path(1).last(), Some("22Z"));
+        assert_eq!(traversal.route(1).directions, "LRLRLR");
+        assert_eq!(traversal.route(1).path, traversal.path(1).collect::<Vec<&str>>());
+
+        let traversal = traverse(&network, &Selector::Exact("22A"), &Selector::Custom(Box::new(|id| id == "22C"))).unwrap();
+        assert_eq!(traversal.steps, 2);
+
+        let traversal = traverse(&network, &Selector::Exact("11Z"), &Selector::Suffix("Z")).unwrap();
+        assert_eq!(traversal.steps, 0);
+    }
+
+    #[test]
+    fn test_shortest_path() {
+        let input = example::input(8, "example2");
+        let network = parse(&input).unwrap();
+
+        let route = shortest_path(&network, "AAA", &Selector::Exact("ZZZ")).unwrap();
+        assert_eq!(route, Route { path: vec!["AAA", "BBB", "ZZZ"], directions: "LR".into() });
+        assert_eq!(route.steps(), 2);
+
+        let route = shortest_path(&network, "ZZZ", &Selector::Exact("ZZZ")).unwrap();
+        assert_eq!(route, Route { path: vec!["ZZZ"], directions: String::new() });
+
+        let ghosts = example::input(8, "example3");
+        let network = parse(&ghosts).unwrap();
+        assert_eq!(shortest_path(&network, "22A", &Selector::Suffix("Z")).unwrap().directions, "LLL");
+
+        let error = shortest_path(&network, "XXX", &Selector::Suffix("Z")).unwrap_err();
+        assert_eq!(error.to_string(), "**Z is unreachable from XXX");
+    }
+
+    #[test]
+    fn test_traverse_errors() {
+        let ghosts = example::input(8, "example3");
+        let network = parse(&ghosts).unwrap();
+
+        let error = traverse(&network, &Selector::Exact("AAA"), &Selector::Exact("ZZZ")).err().unwrap();
+        assert_eq!(error.to_string(), "No nodes match AAA");
+
+        let error = traverse(&network, &Selector::Exact("XXX"), &Selector::Suffix("Z")).err().unwrap();
+        assert_eq!(error.to_string(), "**Z is unreachable from XXX");
+    }
+}
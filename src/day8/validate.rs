@@ -0,0 +1,155 @@
+use std::collections::VecDeque;
+use std::fmt::{Display, Formatter};
+
+use itertools::Itertools;
+
+use crate::day8::network::Network;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Finding {
+    InvalidDirection { column: usize, direction: char },
+    UndefinedReference { line: usize, id: String },
+    DuplicateDefinition { line: usize, first: usize, id: String },
+    Unreachable { line: usize, id: String },
+    MissingStart,
+}
+
+impl Finding {
+    pub fn line(&self) -> Option<usize> {
+        match self {
+            Finding::InvalidDirection { .. } => Some(1),
+            Finding::UndefinedReference { line, .. } => Some(*line),
+            Finding::DuplicateDefinition { line, .. } => Some(*line),
+            Finding::Unreachable { line, .. } => Some(*line),
+            Finding::MissingStart => None,
+        }
+    }
+}
+
+impl Display for Finding {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Finding::InvalidDirection { column, direction } =>
+                write!(f, "line 1, column {column}: invalid direction {direction:?}, expected L or R"),
+            Finding::UndefinedReference { line, id } =>
+                write!(f, "line {line}: reference to undefined node {id}"),
+            Finding::DuplicateDefinition { line, first, id } =>
+                write!(f, "line {line}: {id} is already defined on line {first}"),
+            Finding::Unreachable { line, id } =>
+                write!(f, "line {line}: {id} is unreachable from AAA"),
+            Finding::MissingStart =>
+                write!(f, "AAA is not defined"),
+        }
+    }
+}
+
+fn directions(network: &Network) -> Vec<Finding> {
+    network.directions.chars()
+        .enumerate()
+        .filter(|(_, direction)| !matches!(direction, 'L' | 'R'))
+        .map(|(column, direction)| Finding::InvalidDirection { column: column + 1, direction })
+        .collect()
+}
+
+fn references(network: &Network) -> Vec<Finding> {
+    network.nodes()
+        .flat_map(|node| network.edges(node).into_iter()
+            .unique()
+            .filter(|target| !network.is_defined(*target))
+            .map(move |target| Finding::UndefinedReference {
+                line: network.line(node).expect("Defined nodes have a line"),
+                id: network.name(target).into(),
+            }))
+        .collect()
+}
+
+fn duplicates(network: &Network) -> Vec<Finding> {
+    network.duplicates().iter()
+        .map(|(node, first, line)| Finding::DuplicateDefinition {
+            line: *line,
+            first: *first,
+            id: network.name(*node).into(),
+        })
+        .collect()
+}
+
+fn unreachable(network: &Network) -> Vec<Finding> {
+    let Ok(start) = network.start("AAA") else {
+        return vec![Finding::MissingStart];
+    };
+
+    let mut reached = vec![false; network.len()];
+    let mut queue = VecDeque::from([start]);
+    reached[start] = true;
+
+    while let Some(node) = queue.pop_front() {
+        for target in network.edges(node) {
+            if network.is_defined(target) && !reached[target] {
+                reached[target] = true;
+                queue.push_back(target);
+            }
+        }
+    }
+
+    network.nodes()
+        .filter(|node| !reached[*node])
+        .map(|node| Finding::Unreachable {
+            line: network.line(node).expect("Defined nodes have a line"),
+            id: network.name(node).into(),
+        })
+        .collect()
+}
+
+pub fn validate(network: &Network) -> Vec<Finding> {
+    directions(network).into_iter()
+        .chain(references(network))
+        .chain(duplicates(network))
+        .chain(unreachable(network))
+        .sorted_by_key(Finding::line)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::day8::parse;
+    use crate::example;
+
+    use super::{Finding, validate};
+
+    #[test]
+    fn test_validate() {
+        for name in ["example1", "example2"] {
+            let input = example::input(8, name);
+            assert!(validate(&parse(&input).unwrap()).is_empty());
+        }
+    }
+
+    #[test]
+    fn test_validate_findings() {
+        let network = parse("LXR
+
+AAA = (BBB, CCC)
+BBB = (AAA, AAA)
+DDD = (DDD, EEE)
+BBB = (ZZZ, ZZZ)").unwrap();
+
+        assert_eq!(validate(&network), vec![
+            Finding::InvalidDirection { column: 2, direction: 'X' },
+            Finding::UndefinedReference { line: 3, id: "CCC".into() },
+            Finding::UndefinedReference { line: 5, id: "EEE".into() },
+            Finding::Unreachable { line: 5, id: "DDD".into() },
+            Finding::UndefinedReference { line: 6, id: "ZZZ".into() },
+            Finding::DuplicateDefinition { line: 6, first: 4, id: "BBB".into() },
+        ]);
+
+        assert_eq!(validate(&network)[5].to_string(), "line 6: BBB is already defined on line 4");
+    }
+
+    #[test]
+    fn test_validate_missing_start() {
+        let network = parse("L\n\nBBB = (BBB, BBB)").unwrap();
+
+        assert_eq!(validate(&network), vec![Finding::MissingStart]);
+        assert_eq!(validate(&network)[0].line(), None);
+    }
+}
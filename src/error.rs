@@ -0,0 +1,117 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::ops::Range;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseError {
+    pub day: u8,
+    pub line: usize,
+    pub columns: Range<usize>,
+    pub expected: String,
+    pub source: String,
+}
+
+pub fn offset(line: &str, part: &str) -> usize {
+    let offset = (part.as_ptr() as usize).wrapping_sub(line.as_ptr() as usize);
+    assert!(offset <= line.len(), "{part:?} is not a slice of {line:?}");
+
+    offset
+}
+
+impl ParseError {
+    pub fn new(day: u8, source: &str, columns: Range<usize>, expected: &str) -> ParseError {
+        ParseError {
+            day,
+            line: 1,
+            columns,
+            expected: expected.into(),
+            source: source.into(),
+        }
+    }
+
+    pub fn at(day: u8, source: &str, part: &str, expected: &str) -> ParseError {
+        let start = offset(source, part);
+        ParseError::new(day, source, start..start + part.len(), expected)
+    }
+
+    pub fn at_end(day: u8, source: &str, expected: &str) -> ParseError {
+        ParseError::new(day, source, source.len()..source.len(), expected)
+    }
+
+    pub fn at_line(self, line: usize) -> ParseError {
+        ParseError { line, ..self }
+    }
+
+    pub fn within(self, source: &str, part: &str) -> ParseError {
+        let start = offset(source, part);
+
+        ParseError {
+            columns: self.columns.start + start..self.columns.end + start,
+            source: source.into(),
+            ..self
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let start = self.source.get(..self.columns.start).map_or(0, |prefix| prefix.chars().count());
+        let width = self.source.get(self.columns.clone()).map_or(0, |span| span.chars().count());
+        let number = self.line.to_string();
+        let gutter = " ".repeat(number.len());
+
+        writeln!(f, "Day {}, line {}, column {}: expected {}", self.day, self.line, start + 1, self.expected)?;
+        writeln!(f, "{gutter} |")?;
+        writeln!(f, "{number} | {}", self.source)?;
+        write!(f, "{gutter} | {}{}", " ".repeat(start), "^".repeat(width.max(1)))
+    }
+}
+
+impl Error for ParseError {}
+
+#[cfg(test)]
+mod tests {
+    use super::ParseError;
+
+    #[test]
+    fn test_at() {
+        let line = "32T3K abc";
+        let error = ParseError::at(7, line, &line[6..], "bid").at_line(3);
+
+        assert_eq!(error, ParseError {
+            day: 7,
+            line: 3,
+            columns: 6..9,
+            expected: "bid".into(),
+            source: line.into(),
+        });
+    }
+
+    #[test]
+    fn test_within() {
+        let line = "Game 1: 3 blue, 4 rex";
+        let round = &line[8..];
+        let error = ParseError::at(2, round, &round[10..], "red, green or blue").within(line, round);
+
+        assert_eq!(error.columns, 18..21);
+        assert_eq!(error.source, line);
+    }
+
+    #[test]
+    fn test_display() {
+        let line = "32T3K abc";
+        let error = ParseError::at(7, line, &line[6..], "bid").at_line(12);
+
+        assert_eq!(error.to_string(), "Day 7, line 12, column 7: expected bid
+   |
+12 | 32T3K abc
+   |       ^^^");
+
+        let error = ParseError::at_end(8, "AAA", "` = `");
+
+        assert_eq!(error.to_string(), "Day 8, line 1, column 4: expected ` = `
+  |
+1 | AAA
+  |    ^");
+    }
+}
@@ -0,0 +1,124 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use anyhow::{bail, Context, Result};
+
+use crate::solution::Parsed;
+
+pub const SEPARATOR: &str = "---";
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Example {
+    pub answers: Vec<(u8, String)>,
+    pub input: String,
+}
+
+pub fn examples_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("examples")
+}
+
+fn parse_answer(line: &str) -> Option<(u8, String)> {
+    let (part, answer) = line.split_once('=')?;
+    let part = part.trim().strip_prefix("part")?.parse::<u8>().ok()?;
+
+    Some((part, answer.trim().into()))
+}
+
+impl FromStr for Example {
+    type Err = anyhow::Error;
+
+    fn from_str(input: &str) -> Result<Self> {
+        let (header, input) = input.split_once(&format!("{SEPARATOR}\n"))
+            .with_context(|| format!("Failed to find the `{SEPARATOR}` line ending the answers header"))?;
+
+        let answers = header.lines()
+            .filter(|line| !line.trim().is_empty() && !line.starts_with('#'))
+            .enumerate()
+            .map(|(number, line)| match parse_answer(line) {
+                Some(answer) => Ok(answer),
+                None => bail!("Invalid answer on line {}: {line}", number + 1)
+            })
+            .collect::<Result<Vec<(u8, String)>>>()?;
+
+        Ok(Example { answers, input: input.into() })
+    }
+}
+
+impl Example {
+    pub fn load(path: &Path) -> Result<Example> {
+        fs::read_to_string(path)
+            .with_context(|| format!("Unable to read example: {}", path.display()))?
+            .parse::<Example>()
+            .with_context(|| format!("Unable to parse example: {}", path.display()))
+    }
+
+    pub fn check(&self, parsed: &dyn Parsed) -> Vec<String> {
+        self.answers.iter()
+            .filter_map(|(part, expected)| match parsed.solve(*part) {
+                Ok(answer) if answer == *expected => None,
+                Ok(answer) => Some(format!("part {part}: expected {expected}, got {answer}")),
+                Err(err) => Some(format!("part {part}: expected {expected}, failed with {err:#}")),
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+pub fn input(day: u8, name: &str) -> String {
+    Example::load(&examples_dir().join(format!("day{day}")).join(format!("{name}.txt")))
+        .expect("Example exists")
+        .input
+}
+
+pub fn discover(dir: &Path) -> Result<Vec<(u8, PathBuf)>> {
+    let mut examples = Vec::new();
+
+    for entry in fs::read_dir(dir).with_context(|| format!("Unable to read {}", dir.display()))? {
+        let path = entry?.path();
+
+        let Some(day) = path.file_name()
+            .and_then(|name| name.to_str())
+            .and_then(|name| name.strip_prefix("day"))
+            .and_then(|day| day.parse::<u8>().ok()) else {
+            continue;
+        };
+
+        for file in fs::read_dir(&path)? {
+            let file = file?.path();
+
+            if file.extension().is_some_and(|extension| extension == "txt") {
+                examples.push((day, file));
+            }
+        }
+    }
+
+    examples.sort();
+    Ok(examples)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Example;
+
+    #[test]
+    fn test_parse() {
+        let example = "part1 = 288
+part2 = 71503
+---
+Time:      7  15   30
+Distance:  9  40  200
+".parse::<Example>().unwrap();
+
+        assert_eq!(example, Example {
+            answers: vec![(1, "288".into()), (2, "71503".into())],
+            input: "Time:      7  15   30\nDistance:  9  40  200\n".into(),
+        });
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert!("part1 = 288\nTime: 7".parse::<Example>().is_err());
+        assert!("answer = 288\n---\nTime: 7".parse::<Example>().is_err());
+    }
+}
@@ -0,0 +1,73 @@
+use std::env;
+use std::ffi::OsString;
+use std::fs::File;
+use std::io::{Read, stdin};
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+fn resolve_dir(dir: Option<OsString>) -> PathBuf {
+    dir.map(PathBuf::from)
+        .unwrap_or_else(|| Path::new(env!("CARGO_MANIFEST_DIR")).join("input"))
+}
+
+pub fn input_dir() -> PathBuf {
+    resolve_dir(env::var_os(INPUT_DIR_VAR))
+}
+
+fn read_string(mut reader: impl Read) -> std::io::Result<String> {
+    let mut input = String::new();
+    reader.read_to_string(&mut input)?;
+
+    Ok(input)
+}
+
+pub fn read_input(path: &Path) -> Result<String> {
+    if path == Path::new("-") {
+        return read_string(stdin().lock())
+            .context("Unable to read input from stdin");
+    }
+
+    let file = File::open(path)
+        .with_context(|| format!("Unable to open file: {}", path.display()))?;
+
+    read_string(file)
+        .with_context(|| format!("Unable to read file: {}", path.display()))
+}
+
+pub fn get_input(filename: &str) -> Result<String> {
+    read_input(&input_dir().join(filename))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::fs;
+    use std::path::Path;
+
+    use super::{read_input, resolve_dir};
+
+    #[test]
+    fn test_resolve_dir() {
+        assert_eq!(resolve_dir(Some("/tmp/inputs".into())), Path::new("/tmp/inputs"));
+        assert_eq!(resolve_dir(None), Path::new(env!("CARGO_MANIFEST_DIR")).join("input"));
+    }
+
+    #[test]
+    fn test_read_input() {
+        let path = env::temp_dir().join(format!("aoc-read-input-{}", std::process::id()));
+        fs::write(&path, "LLR\n\nAAA = (BBB, BBB)\n").unwrap();
+
+        assert_eq!(read_input(&path).unwrap(), "LLR\n\nAAA = (BBB, BBB)\n");
+
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_read_missing_input() {
+        let error = read_input(Path::new("/nonexistent/day1")).unwrap_err();
+        assert_eq!(error.to_string(), "Unable to open file: /nonexistent/day1");
+    }
+}
@@ -1,7 +1,4 @@
-use std::fs::File;
-use std::io::{BufRead, BufReader};
-
-use anyhow::{anyhow, Result};
+use crate::solution::Solver;
 
 pub mod day1;
 pub mod day2;
@@ -11,12 +8,29 @@ pub mod day5;
 pub mod day6;
 pub mod day7;
 pub mod day8;
+pub mod error;
+pub mod example;
+pub mod input;
+pub mod pool;
+pub mod runner;
+pub mod solution;
+
+pub struct Day {
+    pub number: u8,
+    pub solver: &'static dyn Solver,
+}
 
-pub fn get_input(filename: &str) -> Result<Vec<String>> {
-    let file = File::open(format!("input/{filename}"))
-        .expect("Unable to open file: {filename}");
+pub const DAYS: &[Day] = &[
+    Day { number: 1, solver: &day1::Day1 },
+    Day { number: 2, solver: &day2::Day2 },
+    Day { number: 3, solver: &day3::Day3 },
+    Day { number: 4, solver: &day4::Day4 },
+    Day { number: 5, solver: &day5::Day5 },
+    Day { number: 6, solver: &day6::Day6 },
+    Day { number: 7, solver: &day7::Day7 },
+    Day { number: 8, solver: &day8::Day8 },
+];
 
-    return BufReader::new(file).lines()
-        .map(|line| line.map_err(|err| anyhow!(err)))
-        .collect::<Result<Vec<String>>>();
+pub fn get_day(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.number == number)
 }
@@ -0,0 +1,18 @@
+use std::env;
+use std::process::ExitCode;
+
+use anyhow::Result;
+
+use aoc::runner;
+use aoc::runner::{analyze, viz};
+use aoc::runner::args::Command;
+
+fn main() -> Result<ExitCode> {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    match Command::parse(&args)? {
+        Command::Run(options) => runner::run(&options),
+        Command::Analyze(options) => analyze::run(&options),
+        Command::Viz(options) => viz::run(&options),
+    }
+}
@@ -0,0 +1,150 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+
+pub struct Pool {
+    jobs: AtomicUsize,
+    busy: AtomicUsize,
+}
+
+struct Workers<'a> {
+    pool: &'a Pool,
+    count: usize,
+}
+
+impl Drop for Workers<'_> {
+    fn drop(&mut self) {
+        self.pool.busy.fetch_sub(self.count, Ordering::SeqCst);
+    }
+}
+
+static POOL: Pool = Pool::new(0);
+
+impl Pool {
+    pub const fn new(jobs: usize) -> Pool {
+        Pool {
+            jobs: AtomicUsize::new(jobs),
+            busy: AtomicUsize::new(0),
+        }
+    }
+
+    fn jobs(&self) -> usize {
+        match self.jobs.load(Ordering::SeqCst) {
+            0 => thread::available_parallelism().map_or(1, usize::from),
+            jobs => jobs,
+        }
+    }
+
+    pub fn set_jobs(&self, jobs: usize) {
+        self.jobs.store(jobs.max(1), Ordering::SeqCst);
+    }
+
+    fn acquire(&self, wanted: usize) -> Workers<'_> {
+        let limit = self.jobs() - 1;
+        let mut busy = self.busy.load(Ordering::SeqCst);
+
+        loop {
+            let count = wanted.min(limit.saturating_sub(busy));
+
+            match self.busy.compare_exchange(busy, busy + count, Ordering::SeqCst, Ordering::SeqCst) {
+                Ok(_) => return Workers { pool: self, count },
+                Err(current) => busy = current,
+            }
+        }
+    }
+
+    pub fn map<T: Sync, R: Send>(&self, items: &[T], f: impl Fn(&T) -> R + Sync) -> Vec<R> {
+        let workers = self.acquire(items.len().saturating_sub(1));
+
+        if workers.count == 0 {
+            return items.iter().map(f).collect();
+        }
+
+        let next = AtomicUsize::new(0);
+        let results: Vec<Mutex<Option<R>>> = items.iter().map(|_| Mutex::new(None)).collect();
+
+        let work = || loop {
+            let index = next.fetch_add(1, Ordering::SeqCst);
+
+            let Some(item) = items.get(index) else {
+                break;
+            };
+
+            *results[index].lock().unwrap() = Some(f(item));
+        };
+
+        thread::scope(|scope| {
+            for _ in 0..workers.count {
+                scope.spawn(work);
+            }
+
+            work();
+        });
+
+        results.into_iter()
+            .map(|result| result.into_inner().unwrap().expect("Every item is mapped"))
+            .collect()
+    }
+}
+
+pub fn set_jobs(jobs: usize) {
+    POOL.set_jobs(jobs)
+}
+
+pub fn map<T: Sync, R: Send>(items: &[T], f: impl Fn(&T) -> R + Sync) -> Vec<R> {
+    POOL.map(items, f)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::thread;
+    use std::time::Duration;
+
+    use super::Pool;
+
+    #[test]
+    fn test_map_preserves_order() {
+        let pool = Pool::new(4);
+        let items: Vec<u64> = (0..100).collect();
+
+        assert_eq!(pool.map(&items, |item| item * 2), (0..200).step_by(2).collect::<Vec<u64>>());
+        assert_eq!(pool.map(&Vec::<u64>::new(), |item| item * 2), vec![]);
+    }
+
+    #[test]
+    fn test_nested_map_shares_workers() {
+        let pool = Pool::new(3);
+        let active = AtomicUsize::new(0);
+        let peak = AtomicUsize::new(0);
+
+        let results = pool.map(&[1, 2, 3, 4], |outer| {
+            pool.map(&[10, 20, 30], |inner| {
+                let current = active.fetch_add(1, Ordering::SeqCst) + 1;
+                peak.fetch_max(current, Ordering::SeqCst);
+                thread::sleep(Duration::from_millis(5));
+                active.fetch_sub(1, Ordering::SeqCst);
+
+                outer * inner
+            })
+        });
+
+        assert_eq!(results, vec![
+            vec![10, 20, 30],
+            vec![20, 40, 60],
+            vec![30, 60, 90],
+            vec![40, 80, 120],
+        ]);
+
+        assert!(peak.load(Ordering::SeqCst) <= 3);
+        assert_eq!(pool.busy.load(Ordering::SeqCst), 0);
+    }
+
+    #[test]
+    fn test_single_job_runs_inline() {
+        let pool = Pool::new(1);
+        let caller = thread::current().id();
+
+        assert!(pool.map(&[1, 2, 3], |_| thread::current().id() == caller).into_iter().all(|inline| inline));
+    }
+}
@@ -0,0 +1,203 @@
+use std::fmt::Write;
+use std::process::ExitCode;
+
+use anyhow::{anyhow, bail, Result};
+
+use crate::day6::{self, Day6, RaceReport};
+use crate::day8::Day8;
+use crate::day8::traverse::{Route, Selector, shortest_path, traverse};
+use crate::day8::validate::{Finding, validate};
+use crate::runner::args::AnalyzeOptions;
+use crate::runner::format::ReportFormat;
+use crate::runner::load_input;
+use crate::solution::Solution;
+
+fn format_window(report: &RaceReport) -> String {
+    report.window.as_ref().map_or("-".into(), |window| format!("{}..={}", window.start(), window.end()))
+}
+
+fn format_race_table(reports: &[RaceReport]) -> String {
+    let mut output = format!("{:>4}  {:>8}  {:>8}  {:>8}  {:>8}  {:>12}  {:>8}  {:>8}\n",
+        "Race", "Time", "Record", "Hold", "Best", "Window", "Margin", "Winners");
+
+    for (race, report) in reports.iter().enumerate() {
+        writeln!(output, "{:>4}  {:>8}  {:>8}  {:>8}  {:>8}  {:>12}  {:>8}  {:>8}",
+            race + 1,
+            report.time,
+            report.record,
+            report.optimal,
+            report.best,
+            format_window(report),
+            report.margin(),
+            report.winners()).unwrap();
+    }
+
+    output
+}
+
+fn format_race_csv(reports: &[RaceReport]) -> String {
+    let mut output = String::from("race,hold,distance,record,margin,wins\n");
+
+    for (race, report) in reports.iter().enumerate() {
+        for (hold, distance) in report.profile() {
+            writeln!(output, "{},{hold},{distance},{},{},{}",
+                race + 1,
+                report.record,
+                distance as i128 - report.record as i128,
+                distance > report.record).unwrap();
+        }
+    }
+
+    output
+}
+
+fn analyze_races(input: &str, format: ReportFormat) -> Result<String> {
+    let reports = day6::analyze(&Day6::parse(input)?)?;
+
+    Ok(match format {
+        ReportFormat::Table => format_race_table(&reports),
+        ReportFormat::Csv => format_race_csv(&reports),
+    })
+}
+
+fn format_route_table(route: &Route, instructions: Option<u64>) -> String {
+    let mut output = format!("{:>5}  {:>5}  {:>11}  {:>12}  {}\n",
+        "Start", "Goal", "Free choice", "Instructions", "Directions");
+
+    writeln!(output, "{:>5}  {:>5}  {:>11}  {:>12}  {}",
+        route.path[0],
+        route.path[route.path.len() - 1],
+        route.steps(),
+        instructions.map_or("-".into(), |steps| steps.to_string()),
+        route.directions).unwrap();
+
+    output
+}
+
+fn format_route_csv(route: &Route) -> String {
+    let mut output = String::from("step,node,direction\n");
+    let directions = route.directions.chars().map(Some).chain([None]);
+
+    for (step, (node, direction)) in route.path.iter().zip(directions).enumerate() {
+        writeln!(output, "{step},{node},{}", direction.map_or(String::new(), String::from)).unwrap();
+    }
+
+    output
+}
+
+fn format_findings(findings: &[Finding]) -> String {
+    let mut output = format!("\n{} problem(s) found in the network:\n", findings.len());
+
+    for finding in findings {
+        writeln!(output, "  {finding}").unwrap();
+    }
+
+    output
+}
+
+fn analyze_network(input: &str, format: ReportFormat) -> Result<String> {
+    let network = Day8::parse(input)?;
+    let findings = validate(&network);
+    let goal = Selector::Exact("ZZZ");
+
+    let route = shortest_path(&network, "AAA", &goal).map_err(|err| if findings.is_empty() {
+        err
+    } else {
+        anyhow!("{err}\n{}", format_findings(&findings))
+    })?;
+
+    let instructions = traverse(&network, &Selector::Exact("AAA"), &goal).ok()
+        .map(|traversal| traversal.steps);
+
+    Ok(match format {
+        ReportFormat::Table if findings.is_empty() => format_route_table(&route, instructions),
+        ReportFormat::Table => format_route_table(&route, instructions) + &format_findings(&findings),
+        ReportFormat::Csv => format_route_csv(&route),
+    })
+}
+
+pub fn run(options: &AnalyzeOptions) -> Result<ExitCode> {
+    let analyze: fn(&str, ReportFormat) -> Result<String> = match options.day {
+        6 => analyze_races,
+        8 => analyze_network,
+        day => bail!("Day {day} has no analysis"),
+    };
+
+    let input = load_input(options.day, options.input.as_deref())?;
+    print!("{}", analyze(&input, options.format)?);
+    Ok(ExitCode::SUCCESS)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::example;
+    use crate::runner::format::ReportFormat;
+
+    use super::{analyze_network, analyze_races};
+
+    #[test]
+    fn test_race_table() {
+        let input = example::input(6, "example");
+        assert_eq!(analyze_races(&input, ReportFormat::Table).unwrap(), "\
+Race      Time    Record      Hold      Best        Window    Margin   Winners
+   1         7         9         3        12         2..=5         3         4
+   2        15        40         7        56        4..=11        16         8
+   3        30       200        15       225       11..=19        25         9
+");
+    }
+
+    #[test]
+    fn test_race_csv() {
+        let input = example::input(6, "example");
+        let csv = analyze_races(&input, ReportFormat::Csv).unwrap();
+        let lines = csv.lines().collect::<Vec<&str>>();
+
+        assert_eq!(lines.len(), 1 + 8 + 16 + 31);
+        assert_eq!(lines[0], "race,hold,distance,record,margin,wins");
+        assert_eq!(lines[1], "1,0,0,9,-9,false");
+        assert_eq!(lines[3], "1,2,10,9,1,true");
+        assert_eq!(lines[24], "2,15,0,40,-40,false");
+        assert_eq!(lines[55], "3,30,0,200,-200,false");
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        assert!(analyze_races("Time: 7", ReportFormat::Table).is_err());
+        assert!(analyze_network("L\n\nAAA = (AAA, AAA)", ReportFormat::Table).is_err());
+    }
+
+    #[test]
+    fn test_network_table() {
+        let input = example::input(8, "example2");
+        assert_eq!(analyze_network(&input, ReportFormat::Table).unwrap(), "\
+Start   Goal  Free choice  Instructions  Directions
+  AAA    ZZZ            2             6  LR
+");
+
+        let unreachable = "L\n\nAAA = (AAA, BBB)\nBBB = (ZZZ, ZZZ)\nZZZ = (ZZZ, ZZZ)";
+        assert!(analyze_network(unreachable, ReportFormat::Table).unwrap().contains(" 2             -  RL"));
+    }
+
+    #[test]
+    fn test_network_findings() {
+        let input = "LR\n\nAAA = (BBB, ZZZ)\nBBB = (ZZZ, ZZZ)\nZZZ = (ZZZ, ZZZ)\nQQQ = (QQQ, QQQ)";
+        assert!(analyze_network(input, ReportFormat::Table).unwrap().ends_with("
+1 problem(s) found in the network:
+  line 6: QQQ is unreachable from AAA
+"));
+
+        let error = analyze_network("LX\n\nBBB = (BBB, BBB)", ReportFormat::Table).unwrap_err();
+        assert_eq!(error.to_string(), "Unknown location AAA
+
+2 problem(s) found in the network:
+  AAA is not defined
+  line 1, column 2: invalid direction 'X', expected L or R
+");
+    }
+
+    #[test]
+    fn test_network_csv() {
+        let input = example::input(8, "example2");
+        assert_eq!(analyze_network(&input, ReportFormat::Csv).unwrap(), "step,node,direction\n0,AAA,L\n1,BBB,R\n2,ZZZ,\n");
+    }
+}
@@ -0,0 +1,163 @@
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use anyhow::{anyhow, bail, Context, Result};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Verdict {
+    Pass,
+    Fail(String),
+    Unknown,
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Pass => write!(f, "pass"),
+            Verdict::Fail(expected) => write!(f, "FAIL, expected {expected}"),
+            Verdict::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Answers {
+    answers: BTreeMap<(u8, u8), String>,
+}
+
+pub fn answers_path() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("answers").join("answers.txt")
+}
+
+fn parse_key(key: &str) -> Option<(u8, u8)> {
+    let (day, part) = key.split_once('.')?;
+
+    Some((
+        day.strip_prefix("day")?.parse::<u8>().ok()?,
+        part.strip_prefix("part")?.parse::<u8>().ok()?,
+    ))
+}
+
+impl Answers {
+    pub fn load(path: &Path) -> Result<Answers> {
+        match fs::read_to_string(path) {
+            Ok(contents) => contents.parse::<Answers>()
+                .with_context(|| format!("Unable to parse answers file: {}", path.display())),
+            Err(err) if err.kind() == ErrorKind::NotFound => Ok(Answers::default()),
+            Err(err) => Err(anyhow!(err))
+                .with_context(|| format!("Unable to read answers file: {}", path.display())),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+
+        fs::write(path, self.to_string())
+            .with_context(|| format!("Unable to write answers file: {}", path.display()))
+    }
+
+    pub fn get(&self, day: u8, part: u8) -> Option<&str> {
+        self.answers.get(&(day, part)).map(String::as_str)
+    }
+
+    pub fn insert(&mut self, day: u8, part: u8, answer: &str) {
+        self.answers.insert((day, part), answer.into());
+    }
+
+    pub fn check(&self, day: u8, part: u8, answer: &str) -> Verdict {
+        match self.get(day, part) {
+            Some(expected) if expected == answer => Verdict::Pass,
+            Some(expected) => Verdict::Fail(expected.into()),
+            None => Verdict::Unknown,
+        }
+    }
+}
+
+impl FromStr for Answers {
+    type Err = anyhow::Error;
+
+    fn from_str(input: &str) -> Result<Self> {
+        let mut answers = Answers::default();
+
+        for (number, line) in input.lines().enumerate() {
+            let line = line.trim();
+
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let Some((day, part, answer)) = line.split_once('=')
+                .and_then(|(key, answer)| parse_key(key.trim())
+                    .map(|(day, part)| (day, part, answer.trim()))) else {
+                bail!("Invalid answer on line {}: {line}", number + 1);
+            };
+
+            answers.insert(day, part, answer);
+        }
+
+        Ok(answers)
+    }
+}
+
+impl Display for Answers {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for ((day, part), answer) in &self.answers {
+            writeln!(f, "day{day}.part{part} = {answer}")?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Answers, Verdict};
+
+    #[test]
+    fn test_parse() {
+        let answers = "# Known answers
+day5.part1 = 35
+day5.part2 = 46
+
+day10.part1=8
+".parse::<Answers>().unwrap();
+
+        assert_eq!(answers.get(5, 1), Some("35"));
+        assert_eq!(answers.get(5, 2), Some("46"));
+        assert_eq!(answers.get(10, 1), Some("8"));
+        assert_eq!(answers.get(10, 2), None);
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert!("day5 = 35".parse::<Answers>().is_err());
+        assert!("day5.part1 35".parse::<Answers>().is_err());
+        assert!("dayfive.part1 = 35".parse::<Answers>().is_err());
+    }
+
+    #[test]
+    fn test_check() {
+        let answers = "day5.part1 = 35".parse::<Answers>().unwrap();
+
+        assert_eq!(answers.check(5, 1, "35"), Verdict::Pass);
+        assert_eq!(answers.check(5, 1, "36"), Verdict::Fail("35".into()));
+        assert_eq!(answers.check(5, 2, "46"), Verdict::Unknown);
+    }
+
+    #[test]
+    fn test_display() {
+        let mut answers = Answers::default();
+        answers.insert(10, 1, "8");
+        answers.insert(5, 2, "46");
+        answers.insert(5, 1, "35");
+
+        assert_eq!(answers.to_string(), "day5.part1 = 35\nday5.part2 = 46\nday10.part1 = 8\n");
+        assert_eq!(answers.to_string().parse::<Answers>().unwrap(), answers);
+    }
+}
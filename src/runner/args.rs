@@ -0,0 +1,279 @@
+use std::path::PathBuf;
+
+use anyhow::{bail, Context, Result};
+
+use crate::runner::format::{Format, ReportFormat};
+
+pub const USAGE: &str = "Usage: aoc run <day|all> [--part <1|2>] [--input <path|->] [--bench <runs>] [--answers <path>] [--record] [--format <text|json|tsv>] [--jobs <threads>]
+       aoc analyze <day> [--input <path|->] [--format <table|csv>]
+       aoc viz <day> [--input <path|->] [--path]";
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Days {
+    All,
+    One(u8),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RunOptions {
+    pub days: Days,
+    pub part: Option<u8>,
+    pub input: Option<PathBuf>,
+    pub bench: usize,
+    pub answers: Option<PathBuf>,
+    pub record: bool,
+    pub format: Format,
+    pub jobs: Option<usize>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AnalyzeOptions {
+    pub day: u8,
+    pub input: Option<PathBuf>,
+    pub format: ReportFormat,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct VizOptions {
+    pub day: u8,
+    pub input: Option<PathBuf>,
+    pub path: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Command {
+    Run(RunOptions),
+    Analyze(AnalyzeOptions),
+    Viz(VizOptions),
+}
+
+fn parse_days(value: Option<&String>) -> Result<Days> {
+    match value.map(String::as_str) {
+        Some("all") => Ok(Days::All),
+        Some(day) => day.parse::<u8>()
+            .map(Days::One)
+            .with_context(|| format!("Invalid day {day}")),
+        None => bail!("Missing day\n{USAGE}")
+    }
+}
+
+fn parse_part(value: Option<&String>) -> Result<u8> {
+    match value.map(String::as_str) {
+        Some("1") => Ok(1),
+        Some("2") => Ok(2),
+        Some(part) => bail!("Invalid part {part}, expected 1 or 2"),
+        None => bail!("Missing part\n{USAGE}")
+    }
+}
+
+fn parse_path(value: Option<&String>) -> Result<PathBuf> {
+    value.map(PathBuf::from)
+        .with_context(|| format!("Missing path\n{USAGE}"))
+}
+
+fn parse_count(value: Option<&String>, name: &str) -> Result<usize> {
+    match value.map(|count| count.parse::<usize>()) {
+        Some(Ok(count)) if count > 0 => Ok(count),
+        Some(_) => bail!("Invalid number of {name} {}, expected a positive integer", value.unwrap()),
+        None => bail!("Missing number of {name}\n{USAGE}")
+    }
+}
+
+fn parse_run(args: &[String]) -> Result<Command> {
+    let mut args = args.iter();
+
+    let mut options = RunOptions {
+        days: parse_days(args.next())?,
+        part: None,
+        input: None,
+        bench: 1,
+        answers: None,
+        record: false,
+        format: Format::Text,
+        jobs: None,
+    };
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--part" | "-p" => options.part = Some(parse_part(args.next())?),
+            "--input" | "-i" => options.input = Some(parse_path(args.next())?),
+            "--bench" | "-b" => options.bench = parse_count(args.next(), "runs")?,
+            "--jobs" | "-j" => options.jobs = Some(parse_count(args.next(), "jobs")?),
+            "--answers" | "-a" => options.answers = Some(parse_path(args.next())?),
+            "--record" => options.record = true,
+            "--format" | "-f" => options.format = args.next()
+                .with_context(|| format!("Missing format\n{USAGE}"))?
+                .parse::<Format>()?,
+            _ => bail!("Unknown option {arg}\n{USAGE}")
+        }
+    }
+
+    if options.days == Days::All && options.input.is_some() {
+        bail!("An input path can only be given when running a single day");
+    }
+
+    if options.record && options.input.is_some() {
+        bail!("Answers can only be recorded for the puzzle inputs");
+    }
+
+    Ok(Command::Run(options))
+}
+
+fn parse_day(value: Option<&String>) -> Result<u8> {
+    match parse_days(value)? {
+        Days::One(day) => Ok(day),
+        Days::All => bail!("Expected a single day\n{USAGE}"),
+    }
+}
+
+fn parse_analyze(args: &[String]) -> Result<Command> {
+    let mut args = args.iter();
+    let day = parse_day(args.next())?;
+
+    let mut options = AnalyzeOptions { day, input: None, format: ReportFormat::Table };
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--input" | "-i" => options.input = Some(parse_path(args.next())?),
+            "--format" | "-f" => options.format = args.next()
+                .with_context(|| format!("Missing format\n{USAGE}"))?
+                .parse::<ReportFormat>()?,
+            _ => bail!("Unknown option {arg}\n{USAGE}")
+        }
+    }
+
+    Ok(Command::Analyze(options))
+}
+
+fn parse_viz(args: &[String]) -> Result<Command> {
+    let mut args = args.iter();
+    let mut options = VizOptions { day: parse_day(args.next())?, input: None, path: false };
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--input" | "-i" => options.input = Some(parse_path(args.next())?),
+            "--path" => options.path = true,
+            _ => bail!("Unknown option {arg}\n{USAGE}")
+        }
+    }
+
+    Ok(Command::Viz(options))
+}
+
+impl Command {
+    pub fn parse(args: &[String]) -> Result<Command> {
+        let (command, args) = args.split_first()
+            .context(USAGE)?;
+
+        match command.as_str() {
+            "run" => parse_run(args),
+            "analyze" => parse_analyze(args),
+            "viz" => parse_viz(args),
+            _ => bail!("Unknown command {command}\n{USAGE}")
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use crate::runner::format::{Format, ReportFormat};
+
+    use super::{AnalyzeOptions, Command, Days, RunOptions, VizOptions};
+
+    fn parse(args: &str) -> anyhow::Result<Command> {
+        Command::parse(&args.split_whitespace().map(String::from).collect::<Vec<String>>())
+    }
+
+    #[test]
+    fn test_parse_run() {
+        assert_eq!(parse("run 5").unwrap(), Command::Run(RunOptions { days: Days::One(5), part: None, input: None, bench: 1, answers: None, record: false, format: Format::Text, jobs: None }));
+        assert_eq!(parse("run 5 --part 2").unwrap(), Command::Run(RunOptions { days: Days::One(5), part: Some(2), input: None, bench: 1, answers: None, record: false, format: Format::Text, jobs: None }));
+        assert_eq!(parse("run all -p 1").unwrap(), Command::Run(RunOptions { days: Days::All, part: Some(1), input: None, bench: 1, answers: None, record: false, format: Format::Text, jobs: None }));
+
+        assert_eq!(parse("run 8 --input -").unwrap(), Command::Run(RunOptions {
+            days: Days::One(8),
+            part: None,
+            input: Some(PathBuf::from("-")),
+            bench: 1,
+            answers: None,
+            record: false,
+            format: Format::Text,
+            jobs: None,
+        }));
+
+        assert_eq!(parse("run all --bench 10").unwrap(), Command::Run(RunOptions {
+            days: Days::All,
+            part: None,
+            input: None,
+            bench: 10,
+            answers: None,
+            record: false,
+            format: Format::Text,
+            jobs: None,
+        }));
+
+        assert_eq!(parse("run 5 --answers answers/day5 --record").unwrap(), Command::Run(RunOptions {
+            days: Days::One(5),
+            part: None,
+            input: None,
+            bench: 1,
+            answers: Some(PathBuf::from("answers/day5")),
+            record: true,
+            format: Format::Text,
+            jobs: None,
+        }));
+
+        assert_eq!(parse("run all --format json --jobs 4").unwrap(), Command::Run(RunOptions {
+            days: Days::All,
+            part: None,
+            input: None,
+            bench: 1,
+            answers: None,
+            record: false,
+            format: Format::Json,
+            jobs: Some(4),
+        }));
+    }
+
+    #[test]
+    fn test_parse_analyze() {
+        assert_eq!(parse("analyze 6").unwrap(), Command::Analyze(AnalyzeOptions { day: 6, input: None, format: ReportFormat::Table }));
+
+        assert_eq!(parse("analyze 6 --format csv -i -").unwrap(), Command::Analyze(AnalyzeOptions {
+            day: 6,
+            input: Some(PathBuf::from("-")),
+            format: ReportFormat::Csv,
+        }));
+    }
+
+    #[test]
+    fn test_parse_viz() {
+        assert_eq!(parse("viz 8").unwrap(), Command::Viz(VizOptions { day: 8, input: None, path: false }));
+        assert_eq!(parse("viz 8 --path -i net").unwrap(), Command::Viz(VizOptions { day: 8, input: Some(PathBuf::from("net")), path: true }));
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert!(parse("").is_err());
+        assert!(parse("walk 5").is_err());
+        assert!(parse("run").is_err());
+        assert!(parse("run five").is_err());
+        assert!(parse("run 5 --part 3").is_err());
+        assert!(parse("run 5 --fast").is_err());
+        assert!(parse("run 5 --input").is_err());
+        assert!(parse("run all --input input/day5").is_err());
+        assert!(parse("run all --bench 0").is_err());
+        assert!(parse("run all --bench many").is_err());
+        assert!(parse("run 5 --input - --record").is_err());
+        assert!(parse("run 5 --format xml").is_err());
+        assert!(parse("run all --jobs 0").is_err());
+        assert!(parse("analyze").is_err());
+        assert!(parse("analyze all").is_err());
+        assert!(parse("analyze 6 --format json").is_err());
+        assert!(parse("analyze 6 --bench 2").is_err());
+        assert!(parse("viz all").is_err());
+        assert!(parse("viz 8 --format csv").is_err());
+    }
+}
@@ -0,0 +1,301 @@
+use std::fmt::Write;
+use std::str::FromStr;
+use std::time::Duration;
+
+use anyhow::bail;
+
+use crate::runner::{DayReport, Outcome, PartReport};
+use crate::runner::answers::Verdict;
+use crate::runner::timing::Timing;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Format {
+    Text,
+    Json,
+    Tsv,
+}
+
+impl FromStr for Format {
+    type Err = anyhow::Error;
+
+    fn from_str(input: &str) -> anyhow::Result<Self> {
+        match input {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "tsv" => Ok(Format::Tsv),
+            _ => bail!("Invalid format {input}, expected text, json or tsv")
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ReportFormat {
+    Table,
+    Csv,
+}
+
+impl FromStr for ReportFormat {
+    type Err = anyhow::Error;
+
+    fn from_str(input: &str) -> anyhow::Result<Self> {
+        match input {
+            "table" => Ok(ReportFormat::Table),
+            "csv" => Ok(ReportFormat::Csv),
+            _ => bail!("Invalid format {input}, expected table or csv")
+        }
+    }
+}
+
+pub fn print_report(report: &DayReport) {
+    println!("Day {}", report.day);
+
+    if let Some(parse) = &report.parse {
+        println!("Parse: {parse}");
+    }
+
+    for part in &report.parts {
+        match (&part.outcome, &part.timing, &part.verdict) {
+            (Outcome::Solved(answer), Some(timing), Some(verdict)) =>
+                println!("Part {}: {answer} ({timing}) [{verdict}]", part.part),
+            (Outcome::Solved(answer), Some(timing), None) =>
+                println!("Part {}: {answer} ({timing})", part.part),
+            (Outcome::Solved(answer), None, _) =>
+                println!("Part {}: {answer}", part.part),
+            (Outcome::Unsolved, _, _) =>
+                println!("Part {}: Not implemented", part.part),
+            (Outcome::Failed(err), _, _) =>
+                println!("Part {}: Error: {err}", part.part),
+        }
+    }
+}
+
+fn format_part(report: &DayReport, part: u8) -> String {
+    match report.part(part) {
+        Some(PartReport { outcome: Outcome::Solved(_), timing: Some(timing), .. }) => format!("{:.2?}", timing.median()),
+        _ => "-".into()
+    }
+}
+
+pub fn print_summary(reports: &[DayReport]) {
+    println!("{:>3}  {:>12}  {:>12}  {:>12}  {:>12}", "Day", "Parse", "Part 1", "Part 2", "Total");
+
+    for report in reports {
+        println!("{:>3}  {:>12}  {:>12}  {:>12}  {:>12}",
+            report.day,
+            report.parse.as_ref().map_or("-".into(), |parse| format!("{:.2?}", parse.median())),
+            format_part(report, 1),
+            format_part(report, 2),
+            format!("{:.2?}", report.total()));
+    }
+
+    let total = reports.iter()
+        .map(DayReport::total)
+        .sum::<Duration>();
+
+    println!("{:>3}  {:>12}  {:>12}  {:>12}  {:>12}", "", "", "", "", format!("{total:.2?}"));
+}
+
+struct Row<'a> {
+    day: u8,
+    part: u8,
+    status: &'static str,
+    answer: Option<&'a str>,
+    verdict: Option<&'static str>,
+    expected: Option<&'a str>,
+    parse: Option<&'a Timing>,
+    timing: Option<&'a Timing>,
+    error: Option<&'a str>,
+}
+
+fn rows(reports: &[DayReport]) -> impl Iterator<Item=Row<'_>> {
+    reports.iter().flat_map(|report| report.parts.iter().map(|part| {
+        let (status, answer, error) = match &part.outcome {
+            Outcome::Solved(answer) => ("ok", Some(answer.as_str()), None),
+            Outcome::Unsolved => ("unsolved", None, None),
+            Outcome::Failed(err) => ("error", None, Some(err.as_str())),
+        };
+
+        let (verdict, expected) = match &part.verdict {
+            Some(Verdict::Pass) => (Some("pass"), None),
+            Some(Verdict::Fail(expected)) => (Some("fail"), Some(expected.as_str())),
+            Some(Verdict::Unknown) => (Some("unknown"), None),
+            None => (None, None),
+        };
+
+        Row {
+            day: report.day,
+            part: part.part,
+            status,
+            answer,
+            verdict,
+            expected,
+            parse: report.parse.as_ref(),
+            timing: part.timing.as_ref(),
+            error,
+        }
+    }))
+}
+
+fn json_string(value: &str) -> String {
+    let mut output = String::with_capacity(value.len() + 2);
+    output.push('"');
+
+    for char in value.chars() {
+        match char {
+            '"' => output.push_str("\\\""),
+            '\\' => output.push_str("\\\\"),
+            '\n' => output.push_str("\\n"),
+            '\r' => output.push_str("\\r"),
+            '\t' => output.push_str("\\t"),
+            char if char.is_control() => write!(output, "\\u{:04x}", char as u32).unwrap(),
+            char => output.push(char),
+        }
+    }
+
+    output.push('"');
+    output
+}
+
+fn json_value(value: Option<&str>) -> String {
+    value.map_or("null".into(), json_string)
+}
+
+fn json_nanos(value: Option<Duration>) -> String {
+    value.map_or("null".into(), |value| value.as_nanos().to_string())
+}
+
+fn format_json(reports: &[DayReport]) -> String {
+    let rows = rows(reports)
+        .map(|row| format!(
+            "  {{\"day\": {}, \"part\": {}, \"status\": {}, \"answer\": {}, \"verdict\": {}, \"expected\": {}, \
+\"runs\": {}, \"parse_ns\": {}, \"min_ns\": {}, \"median_ns\": {}, \"mean_ns\": {}, \"error\": {}}}",
+            row.day,
+            row.part,
+            json_string(row.status),
+            json_value(row.answer),
+            json_value(row.verdict),
+            json_value(row.expected),
+            row.timing.map_or(0, Timing::runs),
+            json_nanos(row.parse.map(Timing::median)),
+            json_nanos(row.timing.map(Timing::min)),
+            json_nanos(row.timing.map(Timing::median)),
+            json_nanos(row.timing.map(Timing::mean)),
+            json_value(row.error)))
+        .collect::<Vec<String>>();
+
+    if rows.is_empty() {
+        "[]".into()
+    } else {
+        format!("[\n{}\n]", rows.join(",\n"))
+    }
+}
+
+pub fn print_json(reports: &[DayReport]) {
+    println!("{}", format_json(reports));
+}
+
+fn tsv_value(value: Option<&str>) -> String {
+    value.map_or(String::new(), |value| value.replace(['\t', '\n', '\r'], " "))
+}
+
+fn tsv_nanos(value: Option<Duration>) -> String {
+    value.map_or(String::new(), |value| value.as_nanos().to_string())
+}
+
+fn format_tsv(reports: &[DayReport]) -> String {
+    let mut output = String::from("day\tpart\tstatus\tanswer\tverdict\texpected\truns\tparse_ns\tmin_ns\tmedian_ns\tmean_ns\terror\n");
+
+    for row in rows(reports) {
+        writeln!(output, "{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}",
+            row.day,
+            row.part,
+            row.status,
+            tsv_value(row.answer),
+            tsv_value(row.verdict),
+            tsv_value(row.expected),
+            row.timing.map_or(0, Timing::runs),
+            tsv_nanos(row.parse.map(Timing::median)),
+            tsv_nanos(row.timing.map(Timing::min)),
+            tsv_nanos(row.timing.map(Timing::median)),
+            tsv_nanos(row.timing.map(Timing::mean)),
+            tsv_value(row.error)).unwrap();
+    }
+
+    output
+}
+
+pub fn print_tsv(reports: &[DayReport]) {
+    print!("{}", format_tsv(reports));
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::runner::{DayReport, Outcome, PartReport};
+    use crate::runner::answers::Verdict;
+    use crate::runner::timing::Timing;
+
+    use super::{format_json, format_tsv, json_string};
+
+    fn reports() -> Vec<DayReport> {
+        vec![
+            DayReport {
+                day: 5,
+                parse: Some(Timing::new(vec![Duration::from_nanos(100)])),
+                parts: vec![
+                    PartReport {
+                        part: 1,
+                        outcome: Outcome::Solved("35".into()),
+                        timing: Some(Timing::new(vec![Duration::from_nanos(20)])),
+                        verdict: Some(Verdict::Fail("36".into())),
+                    },
+                ],
+            },
+            DayReport {
+                day: 8,
+                parse: Some(Timing::new(vec![Duration::from_nanos(50)])),
+                parts: vec![
+                    PartReport {
+                        part: 1,
+                        outcome: Outcome::Failed("Failed to find ZZZ".into()),
+                        timing: Some(Timing::new(vec![Duration::from_nanos(30)])),
+                        verdict: None,
+                    },
+                    PartReport {
+                        part: 2,
+                        outcome: Outcome::Unsolved,
+                        timing: Some(Timing::new(vec![Duration::from_nanos(1)])),
+                        verdict: None,
+                    },
+                ],
+            },
+        ]
+    }
+
+    #[test]
+    fn test_json_string() {
+        assert_eq!(json_string("Failed to split \"32T3K\"\n"), "\"Failed to split \\\"32T3K\\\"\\n\"");
+        assert_eq!(json_string("a\\b\u{1}"), "\"a\\\\b\\u0001\"");
+    }
+
+    #[test]
+    fn test_format_json() {
+        assert_eq!(format_json(&reports()), r#"[
+  {"day": 5, "part": 1, "status": "ok", "answer": "35", "verdict": "fail", "expected": "36", "runs": 1, "parse_ns": 100, "min_ns": 20, "median_ns": 20, "mean_ns": 20, "error": null},
+  {"day": 8, "part": 1, "status": "error", "answer": null, "verdict": null, "expected": null, "runs": 1, "parse_ns": 50, "min_ns": 30, "median_ns": 30, "mean_ns": 30, "error": "Failed to find ZZZ"},
+  {"day": 8, "part": 2, "status": "unsolved", "answer": null, "verdict": null, "expected": null, "runs": 1, "parse_ns": 50, "min_ns": 1, "median_ns": 1, "mean_ns": 1, "error": null}
+]"#);
+
+        assert_eq!(format_json(&[]), "[]");
+    }
+
+    #[test]
+    fn test_format_tsv() {
+        assert_eq!(format_tsv(&reports()), "day\tpart\tstatus\tanswer\tverdict\texpected\truns\tparse_ns\tmin_ns\tmedian_ns\tmean_ns\terror
+5\t1\tok\t35\tfail\t36\t1\t100\t20\t20\t20\t
+8\t1\terror\t\t\t\t1\t50\t30\t30\t30\tFailed to find ZZZ
+8\t2\tunsolved\t\t\t\t1\t50\t1\t1\t1\t
+");
+    }
+}
@@ -0,0 +1,183 @@
+use std::path::Path;
+use std::process::ExitCode;
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+
+use crate::{Day, DAYS, get_day};
+use crate::input::{get_input, read_input};
+use crate::pool;
+use crate::runner::answers::{Answers, answers_path, Verdict};
+use crate::runner::args::{Days, RunOptions};
+use crate::runner::format::{Format, print_json, print_report, print_summary, print_tsv};
+use crate::runner::timing::{measure, Timing};
+use crate::solution::Unsolved;
+
+pub mod analyze;
+pub mod answers;
+pub mod args;
+pub mod format;
+pub mod timing;
+pub mod viz;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Outcome {
+    Solved(String),
+    Unsolved,
+    Failed(String),
+}
+
+impl From<Result<String>> for Outcome {
+    fn from(result: Result<String>) -> Self {
+        match result {
+            Ok(answer) => Outcome::Solved(answer),
+            Err(err) if err.is::<Unsolved>() => Outcome::Unsolved,
+            Err(err) => Outcome::Failed(format!("{err:#}")),
+        }
+    }
+}
+
+pub struct PartReport {
+    pub part: u8,
+    pub outcome: Outcome,
+    pub timing: Option<Timing>,
+    pub verdict: Option<Verdict>,
+}
+
+pub struct DayReport {
+    pub day: u8,
+    pub parse: Option<Timing>,
+    pub parts: Vec<PartReport>,
+}
+
+impl DayReport {
+    fn failed(day: u8, parse: Option<Timing>, parts: &[u8], err: &anyhow::Error) -> DayReport {
+        let parts = parts.iter()
+            .map(|part| PartReport {
+                part: *part,
+                outcome: Outcome::Failed(format!("{err:#}")),
+                timing: None,
+                verdict: None,
+            })
+            .collect();
+
+        DayReport { day, parse, parts }
+    }
+
+    pub fn part(&self, part: u8) -> Option<&PartReport> {
+        self.parts.iter().find(|report| report.part == part)
+    }
+
+    pub fn total(&self) -> Duration {
+        self.parts.iter()
+            .filter_map(|part| part.timing.as_ref())
+            .chain(self.parse.as_ref())
+            .map(Timing::median)
+            .sum()
+    }
+
+    fn is_failure(&self) -> bool {
+        self.parts.iter().any(|part|
+            matches!(part.outcome, Outcome::Failed(_)) || matches!(part.verdict, Some(Verdict::Fail(_))))
+    }
+}
+
+fn select(days: Days) -> Result<Vec<&'static Day>> {
+    match days {
+        Days::All => Ok(DAYS.iter().collect()),
+        Days::One(number) => get_day(number)
+            .map(|day| vec![day])
+            .with_context(|| format!("Day {number} has not been solved")),
+    }
+}
+
+fn load_input(day: u8, path: Option<&Path>) -> Result<String> {
+    match path {
+        Some(path) => read_input(path),
+        None => get_input(&format!("day{day}")),
+    }
+}
+
+fn solve(day: &Day, options: &RunOptions) -> DayReport {
+    let parts = options.part.map_or(vec![1, 2], |part| vec![part]);
+
+    let input = match load_input(day.number, options.input.as_deref()) {
+        Ok(input) => input,
+        Err(err) => return DayReport::failed(day.number, None, &parts, &err),
+    };
+
+    let (parsed, parse) = measure(options.bench, || day.solver.parse(&input));
+
+    let parsed = match parsed {
+        Ok(parsed) => parsed,
+        Err(err) => return DayReport::failed(day.number, Some(parse), &parts, &err),
+    };
+
+    let parts = pool::map(&parts, |part| {
+        let (answer, timing) = measure(options.bench, || parsed.solve(*part));
+        PartReport { part: *part, outcome: answer.into(), timing: Some(timing), verdict: None }
+    });
+
+    DayReport { day: day.number, parse: Some(parse), parts }
+}
+
+fn verify(report: &mut DayReport, answers: &mut Answers, options: &RunOptions) {
+    for part in &mut report.parts {
+        let Outcome::Solved(answer) = &part.outcome else {
+            continue;
+        };
+
+        if options.record {
+            answers.insert(report.day, part.part, answer);
+        } else if options.input.is_none() {
+            part.verdict = Some(answers.check(report.day, part.part, answer));
+        }
+    }
+}
+
+pub fn run(options: &RunOptions) -> Result<ExitCode> {
+    let path = options.answers.clone().unwrap_or_else(answers_path);
+    let mut answers = Answers::load(&path)?;
+
+    if options.bench > 1 {
+        if options.jobs.is_some_and(|jobs| jobs > 1) {
+            eprintln!("Ignoring --jobs: --bench runs on a single thread so timings aren't skewed");
+        }
+
+        pool::set_jobs(1);
+    } else if let Some(jobs) = options.jobs {
+        pool::set_jobs(jobs);
+    }
+
+    let mut reports = pool::map(&select(options.days)?, |day| solve(day, options));
+
+    for (i, report) in reports.iter_mut().enumerate() {
+        verify(report, &mut answers, options);
+
+        if options.format == Format::Text {
+            if i > 0 {
+                println!();
+            }
+
+            print_report(report);
+        }
+    }
+
+    match options.format {
+        Format::Text if options.days == Days::All => {
+            println!();
+            print_summary(&reports);
+        }
+        Format::Json => print_json(&reports),
+        Format::Tsv => print_tsv(&reports),
+        Format::Text => {}
+    }
+
+    if options.record {
+        answers.save(&path)?;
+        eprintln!("Recorded answers to {}", path.display());
+    }
+
+    let failed = reports.iter().any(DayReport::is_failure);
+    Ok(if failed { ExitCode::FAILURE } else { ExitCode::SUCCESS })
+}
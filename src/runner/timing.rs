@@ -0,0 +1,97 @@
+use std::fmt::{Display, Formatter};
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Timing {
+    samples: Vec<Duration>,
+}
+
+impl Timing {
+    pub fn new(mut samples: Vec<Duration>) -> Timing {
+        assert!(!samples.is_empty(), "Timing requires at least one sample");
+        samples.sort();
+
+        Timing { samples }
+    }
+
+    pub fn runs(&self) -> usize {
+        self.samples.len()
+    }
+
+    pub fn min(&self) -> Duration {
+        self.samples[0]
+    }
+
+    pub fn median(&self) -> Duration {
+        let middle = self.samples.len() / 2;
+
+        if self.samples.len().is_multiple_of(2) {
+            (self.samples[middle - 1] + self.samples[middle]) / 2
+        } else {
+            self.samples[middle]
+        }
+    }
+
+    pub fn mean(&self) -> Duration {
+        self.samples.iter().sum::<Duration>() / self.samples.len() as u32
+    }
+}
+
+impl Display for Timing {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.runs() == 1 {
+            write!(f, "{:.2?}", self.min())
+        } else {
+            write!(f, "min {:.2?}, median {:.2?}, mean {:.2?} over {} runs",
+                self.min(), self.median(), self.mean(), self.runs())
+        }
+    }
+}
+
+pub fn measure<T>(runs: usize, mut f: impl FnMut() -> T) -> (T, Timing) {
+    let mut samples = Vec::with_capacity(runs);
+    let mut result = None;
+
+    for _ in 0..runs.max(1) {
+        let start = Instant::now();
+        result = Some(f());
+        samples.push(start.elapsed());
+    }
+
+    (result.unwrap(), Timing::new(samples))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{measure, Timing};
+
+    #[test]
+    fn test_statistics() {
+        let timing = Timing::new(vec![
+            Duration::from_millis(4),
+            Duration::from_millis(1),
+            Duration::from_millis(10),
+            Duration::from_millis(3),
+        ]);
+
+        assert_eq!(timing.runs(), 4);
+        assert_eq!(timing.min(), Duration::from_millis(1));
+        assert_eq!(timing.median(), Duration::from_micros(3500));
+        assert_eq!(timing.mean(), Duration::from_millis(18) / 4);
+
+        let timing = Timing::new(vec![Duration::from_millis(2), Duration::from_millis(9), Duration::from_millis(5)]);
+        assert_eq!(timing.median(), Duration::from_millis(5));
+    }
+
+    #[test]
+    fn test_measure() {
+        let mut calls = 0;
+        let (result, timing) = measure(5, || { calls += 1; calls * 2 });
+
+        assert_eq!(calls, 5);
+        assert_eq!(result, 10);
+        assert_eq!(timing.runs(), 5);
+    }
+}
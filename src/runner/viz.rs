@@ -0,0 +1,48 @@
+use std::process::ExitCode;
+
+use anyhow::{bail, Result};
+
+use crate::day8::{Day8, dot};
+use crate::day8::traverse::{Route, Selector, traverse};
+use crate::runner::args::VizOptions;
+use crate::runner::load_input;
+use crate::solution::Solution;
+
+fn network(input: &str, path: bool) -> Result<String> {
+    let network = Day8::parse(input)?;
+
+    let route = if path {
+        traverse(&network, &Selector::Exact("AAA"), &Selector::Exact("ZZZ"))?.route(0)
+    } else {
+        Route::default()
+    };
+
+    Ok(dot::to_dot(&network, &route))
+}
+
+pub fn run(options: &VizOptions) -> Result<ExitCode> {
+    let visualize: fn(&str, bool) -> Result<String> = match options.day {
+        8 => network,
+        day => bail!("Day {day} has no visualisation"),
+    };
+
+    let input = load_input(options.day, options.input.as_deref())?;
+    print!("{}", visualize(&input, options.path)?);
+    Ok(ExitCode::SUCCESS)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::example;
+
+    use super::network;
+
+    #[test]
+    fn test_network() {
+        let input = example::input(8, "example2");
+        assert!(!network(&input, false).unwrap().contains("color=red"));
+        assert!(network(&input, true).unwrap().contains(r#""BBB" -> "ZZZ" [label="R", color=red, penwidth=2];"#));
+        assert!(network(&input, true).unwrap().contains(r#""ZZZ" -> "ZZZ" [label="L"];"#));
+        assert!(network("L\n\nAAA = (AAA, AAA)", true).is_err());
+    }
+}
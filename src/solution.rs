@@ -0,0 +1,87 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+use anyhow::{bail, Result};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Unsolved;
+
+impl Display for Unsolved {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Not implemented")
+    }
+}
+
+impl Error for Unsolved {}
+
+pub trait Solution {
+    type Input<'a>: Sync;
+    type Answer: Display;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>>;
+
+    fn part1(_input: &Self::Input<'_>) -> Result<Self::Answer> {
+        Err(Unsolved.into())
+    }
+
+    fn part2(_input: &Self::Input<'_>) -> Result<Self::Answer> {
+        Err(Unsolved.into())
+    }
+}
+
+pub trait Parsed: Sync {
+    fn solve(&self, part: u8) -> Result<String>;
+}
+
+pub trait Solver: Sync {
+    fn parse<'a>(&self, input: &'a str) -> Result<Box<dyn Parsed + 'a>>;
+}
+
+struct Input<'a, S: Solution>(S::Input<'a>);
+
+impl<S: Solution> Parsed for Input<'_, S> {
+    fn solve(&self, part: u8) -> Result<String> {
+        match part {
+            1 => S::part1(&self.0).map(|answer| answer.to_string()),
+            2 => S::part2(&self.0).map(|answer| answer.to_string()),
+            _ => bail!("Invalid part {part}")
+        }
+    }
+}
+
+impl<S: Solution + Sync + 'static> Solver for S {
+    fn parse<'a>(&self, input: &'a str) -> Result<Box<dyn Parsed + 'a>> {
+        Ok(Box::new(Input::<S>(S::parse(input)?)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+
+    use super::{Solution, Solver, Unsolved};
+
+    struct Lengths;
+
+    impl Solution for Lengths {
+        type Input<'a> = Vec<&'a str>;
+        type Answer = usize;
+
+        fn parse(input: &str) -> Result<Vec<&str>> {
+            Ok(input.lines().collect())
+        }
+
+        fn part1(input: &Vec<&str>) -> Result<usize> {
+            Ok(input.iter().map(|line| line.len()).sum())
+        }
+    }
+
+    #[test]
+    fn test_solve() {
+        let input = Lengths.parse("ab\ncde").unwrap();
+
+        assert_eq!(input.solve(1).unwrap(), "5");
+        assert!(input.solve(2).unwrap_err().is::<Unsolved>());
+        assert!(input.solve(3).is_err());
+    }
+}
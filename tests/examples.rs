@@ -0,0 +1,34 @@
+use aoc::example::{discover, Example, examples_dir};
+use aoc::get_day;
+
+#[test]
+fn test_examples() {
+    let examples = discover(&examples_dir()).unwrap();
+    assert!(!examples.is_empty(), "No examples found in {}", examples_dir().display());
+
+    let failures = examples.iter()
+        .flat_map(|(day, path)| {
+            let name = path.strip_prefix(examples_dir()).unwrap_or(path).display().to_string();
+
+            let Some(solution) = get_day(*day) else {
+                return vec![format!("{name}: day {day} is not registered")];
+            };
+
+            let example = match Example::load(path) {
+                Ok(example) => example,
+                Err(err) => return vec![format!("{name}: {err:#}")],
+            };
+
+            let failures = match solution.solver.parse(&example.input) {
+                Ok(parsed) => example.check(parsed.as_ref()),
+                Err(err) => vec![format!("failed to parse with {err:#}")],
+            };
+
+            failures.into_iter()
+                .map(|failure| format!("{name}: {failure}"))
+                .collect()
+        })
+        .collect::<Vec<String>>();
+
+    assert!(failures.is_empty(), "{} example(s) failed:\n{}", failures.len(), failures.join("\n"));
+}